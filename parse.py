@@ -6,7 +6,7 @@ from solana.publickey import PublicKey
 from spl.token.instructions import get_associated_token_address
 from base64 import b64decode, b64encode
 from base58 import b58decode, b58encode
-from construct import RepeatUntil, Struct, BytesInteger, Bytes, Adapter, this, PascalString, Array, Union
+from construct import RepeatUntil, Struct, BytesInteger, Bytes, Adapter, this, PascalString, Array, Union, If, PrefixedArray, Switch
 from construct import setGlobalPrintFullStrings
 
 
@@ -43,15 +43,76 @@ class PositionAdapter(Adapter):
         elif obj == 1:
             return "Manager"
 
+class VoteWeightAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Headcount"
+        elif obj == 1:
+            return "Token"
+        elif obj == 2:
+            return "Quadratic"
+
+class QuorumKindAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Headcount"
+        elif obj == 1:
+            return "Weight"
+
+class LockModeAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Burn"
+        elif obj == 1:
+            return "Escrow"
+        elif obj == 2:
+            return "Treasury"
+
+class TallyModeAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "EarlyQuorum"
+        elif obj == 1:
+            return "FullPeriod"
+        elif obj == 2:
+            return "Decisive"
+
+class JoinPolicyAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Open"
+        elif obj == 1:
+            return "Manager"
+        elif obj == 2:
+            return "Vote"
+
+class TokenGateAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "None"
+        elif obj == 1:
+            return "Balance"
+        elif obj == 2:
+            return "Stake"
+
+class SlashModeAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Burn"
+        elif obj == 1:
+            return "Treasury"
+
+rate_schema = Struct(
+    "numerator" / BytesInteger(1),
+    "denominator" / BytesInteger(1),
+)
+
 group_account_schema = Struct(
     Bytes(8),
     "seed" / BytesInteger(1),
     "electing" / BooleanAdapter(BytesInteger(1)),
     "freeze" / BooleanAdapter(BytesInteger(1)),
-    "rate" / Struct(
-        "numerator" / BytesInteger(1),
-        "denominator" / BytesInteger(1),
-    ),
+    "rate" / rate_schema,
     "update" / BooleanAdapter(BytesInteger(1)),
     Bytes(2),
     "maxManager" / BytesInteger(4, swapped=True),
@@ -61,41 +122,106 @@ group_account_schema = Struct(
     "index" / BytesInteger(4, swapped=True),
     "sponsor" / PubkeyAdapter(Bytes(32)),
     "admin" / PubkeyAdapter(Bytes(32)),
+    "stakes" / BytesInteger(4, swapped=True),
+    "config" / Struct(
+        "quorumWeight" / BytesInteger(8, swapped=True),
+        "timelock" / BytesInteger(8, signed=True, swapped=True),
+        "revealPeriod" / BytesInteger(8, signed=True, swapped=True),
+        "bond" / BytesInteger(8, swapped=True),
+        "draftPeriod" / BytesInteger(8, signed=True, swapped=True),
+        "weight" / VoteWeightAdapter(BytesInteger(1)),
+        "quorum" / QuorumKindAdapter(BytesInteger(1)),
+        "lock" / LockModeAdapter(BytesInteger(1)),
+        "tally" / TallyModeAdapter(BytesInteger(1)),
+        "slash" / SlashModeAdapter(BytesInteger(1)),
+        "sponsors" / BytesInteger(1),
+        "thresholds" / Struct(
+            "upgrade" / rate_schema,
+            "downgrade" / rate_schema,
+            "updateGroup" / rate_schema,
+            "reElection" / rate_schema,
+            "withdraw" / rate_schema,
+            "execute" / rate_schema,
+            "election" / rate_schema,
+            "remove" / rate_schema,
+        ),
+        Bytes(2),
+    ),
+    "membership" / Struct(
+        "gateAmount" / BytesInteger(8, swapped=True),
+        "gateMint" / PubkeyAdapter(Bytes(32)),
+        "allowlist" / Bytes(32),
+        "policy" / JoinPolicyAdapter(BytesInteger(1)),
+        "gate" / TokenGateAdapter(BytesInteger(1)),
+        "threshold" / rate_schema,
+        Bytes(4),
+    ),
+)
+
+# Borsh enums are a 1-byte variant tag followed by the fields of that variant only
+proposal_type_schema = Struct(
+    "kind" / BytesInteger(1),
+    "args" / Switch(this.kind, {
+        2: Struct(
+            "maxManager" / BytesInteger(4, swapped=True),
+        ),
+        4: Struct(
+            "mint" / PubkeyAdapter(Bytes(32)),
+            "receiver" / PubkeyAdapter(Bytes(32)),
+            "amount" / BytesInteger(8, swapped=True),
+        ),
+        5: Struct(
+            "instructions" / BytesInteger(1),
+        ),
+        6: Struct(
+            "candidates" / BytesInteger(1),
+            "seats" / BytesInteger(1),
+            "ranked" / BooleanAdapter(BytesInteger(1)),
+        ),
+        7: Struct(
+            "options" / BytesInteger(1),
+            "metadata" / Bytes(32),
+        ),
+    }),
 )
 
 class ProposalTypeAdapter(Adapter):
+    kinds = ["Upgrade", "Downgrade", "UpdateGroup", "ReElection", "Withdraw", "Execute", "Election", "Signal", "Remove"]
+
     def _decode(self, obj, ctx, path):
-        kind = BytesInteger(1).parse(obj)
-        if kind == 0:
-            return "Upgrade"
-        elif kind == 1:
-            return "Downgrade"
-        elif kind == 2:
-            return Struct(
-                Bytes(4),
-                "maxManager" / BytesInteger(4, swapped=True),
-            ).parse(obj)
-        elif kind == 3:
-            return "ReElection"
-        elif kind == 4:
-            return Struct(
-                Bytes(8),
-                "mint" / PubkeyAdapter(Bytes(32)),
-                "receiver" / PubkeyAdapter(Bytes(32)),
-                "amount" / BytesInteger(8, swapped=True),
-            ).parse(obj)
+        kind = self.kinds[obj.kind]
+        if obj.args is None:
+            return kind
+        return {kind: obj.args}
+
+timestamp_schema = TimestampAdapter(BytesInteger(8, signed=True, swapped=True))
+
+proposal_status_schema = Struct(
+    "kind" / BytesInteger(1),
+    "args" / Switch(this.kind, {
+        1: Struct("time" / timestamp_schema),
+        2: Struct("time" / timestamp_schema),
+        3: Struct("time" / timestamp_schema),
+        4: Struct("time" / timestamp_schema),
+        5: Struct("eta" / timestamp_schema),
+        6: Struct("time" / timestamp_schema),
+        7: Struct("time" / timestamp_schema),
+        8: Struct(
+            "time" / timestamp_schema,
+            "by" / PubkeyAdapter(Bytes(32)),
+        ),
+        9: Struct("expire" / timestamp_schema),
+    }),
+)
 
 class ProposalStatusAdapter(Adapter):
+    kinds = ["Progressing", "Passed", "Rejected", "Updated", "Expired", "Queued", "Canceled", "Closed", "Vetoed", "Draft"]
+
     def _decode(self, obj, ctx, path):
-        status = BytesInteger(1).parse(obj)
-        if status == 0:
-            return "Progressing"
-        elif status == 1:
-            return "Passed"
-        elif status == 2:
-            return "Rejected"
-        elif status == 3:
-            return "Updated"
+        kind = self.kinds[obj.kind]
+        if obj.args is None:
+            return kind
+        return {kind: obj.args}
 
 proposal_account_schema = Struct(
     Bytes(8),
@@ -105,12 +231,31 @@ proposal_account_schema = Struct(
     "group" / PubkeyAdapter(Bytes(32)),
     "positive" / BytesInteger(4, swapped=True),
     "negative" / BytesInteger(4, swapped=True),
+    "abstain" / BytesInteger(4, swapped=True),
+    "voters" / BytesInteger(4, swapped=True),
+    "positive_weight" / BytesInteger(8, swapped=True),
+    "negative_weight" / BytesInteger(8, swapped=True),
+    "abstain_weight" / BytesInteger(8, swapped=True),
+    "choices" / Array(8, BytesInteger(8, swapped=True)),
     "limit" / BytesInteger(8, swapped=True),
     "deadline" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
     "revoke_timeout" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
     "close_timeout" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
-    "type" / ProposalTypeAdapter(Bytes(80)),
-    "status" / ProposalStatusAdapter(Bytes(16)),
+    "snapshot_at" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
+    "reveal_period" / BytesInteger(8, signed=True, swapped=True),
+    "escrowed" / BytesInteger(8, swapped=True),
+    "bond" / BytesInteger(8, swapped=True),
+    "index" / BytesInteger(4, swapped=True),
+    "seed" / BytesInteger(1),
+    "lock" / LockModeAdapter(BytesInteger(1)),
+    "sponsors" / BytesInteger(2, swapped=True),
+    "payload_ready" / BooleanAdapter(BytesInteger(1)),
+    "voted" / BooleanAdapter(BytesInteger(1)),
+    "type" / ProposalTypeAdapter(proposal_type_schema),
+    "status" / ProposalStatusAdapter(proposal_status_schema),
+    "title" / PascalString(BytesInteger(4, swapped=True), "utf8"),
+    "uri" / PascalString(BytesInteger(4, swapped=True), "utf8"),
+    "content_hash" / Bytes(32),
 )
 
 member_account_schema = Struct(
@@ -118,9 +263,39 @@ member_account_schema = Struct(
     "position" / PositionAdapter(BytesInteger(1)),
     "in_promotion" / BooleanAdapter(BytesInteger(1)),
     "in_withdraw" / BooleanAdapter(BytesInteger(1)),
+    "council" / BooleanAdapter(BytesInteger(1)),
     "group" / PubkeyAdapter(Bytes(32)),
     "funder" / PubkeyAdapter(Bytes(32)),
     "owner" / PubkeyAdapter(Bytes(32)),
+    "joined_at" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
+    "positioned_at" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
+    "staked" / BytesInteger(8, swapped=True),
+    "stake_mint" / PubkeyAdapter(Bytes(32)),
+    "invite" / PubkeyAdapter(Bytes(32)),
+    "inviter" / PubkeyAdapter(Bytes(32)),
+)
+
+class SignTypeAdapter(Adapter):
+    def _decode(self, obj, ctx, path):
+        if obj == 0:
+            return "Agreed"
+        elif obj == 1:
+            return "Denied"
+        elif obj == 2:
+            return "Abstain"
+
+signature_account_schema = Struct(
+    Bytes(8),
+    "signer" / PubkeyAdapter(Bytes(32)),
+    "created_at" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
+    "amount" / BytesInteger(8, swapped=True),
+    "weight" / BytesInteger(8, swapped=True),
+    "proposal" / PubkeyAdapter(Bytes(32)),
+    "member" / PubkeyAdapter(Bytes(32)),
+    "sign_type" / SignTypeAdapter(BytesInteger(1)),
+    "committed" / BooleanAdapter(BytesInteger(1)),
+    "commitment" / If(this.committed, Bytes(32)),
+    "choices" / PrefixedArray(BytesInteger(4, swapped=True), BytesInteger(1)),
 )
 
 admin_account_schema = Struct(
@@ -146,6 +321,14 @@ class ProposalEventTypeAdapter(Adapter):
             return "UpdateGroup"
         elif obj == 4:
             return "ReElection"
+        elif obj == 5:
+            return "Execute"
+        elif obj == 6:
+            return "Election"
+        elif obj == 7:
+            return "Signal"
+        elif obj == 8:
+            return "Remove"
 
 submit_proposal_event_schema = Struct(
     Bytes(8),
@@ -157,6 +340,25 @@ submit_proposal_event_schema = Struct(
     "group" / PubkeyAdapter(Bytes(32)),
     "proposal" / PubkeyAdapter(Bytes(32)),
     "deadline" / TimestampAdapter(BytesInteger(8, signed=True, swapped=True)),
+    "title" / PascalString(BytesInteger(4, swapped=True), "utf8"),
+    "uri" / PascalString(BytesInteger(4, swapped=True), "utf8"),
+    "content_hash" / Bytes(32),
+    "label" / PascalString(BytesInteger(4, swapped=True), "utf8"),
+)
+
+sign_proposal_event_schema = Struct(
+    Bytes(8),
+    "ptype" / ProposalEventTypeAdapter(BytesInteger(1)),
+    "submitter" / PubkeyAdapter(Bytes(32)),
+    "beneficiary" / PubkeyAdapter(Bytes(32)),
+    "group" / PubkeyAdapter(Bytes(32)),
+    "proposal" / PubkeyAdapter(Bytes(32)),
+    "stype" / SignTypeAdapter(BytesInteger(1)),
+    "amount" / BytesInteger(8, swapped=True),
+    "weight" / BytesInteger(8, swapped=True),
+    "signer" / PubkeyAdapter(Bytes(32)),
+    "member" / PubkeyAdapter(Bytes(32)),
+    "signature" / PubkeyAdapter(Bytes(32)),
     "label" / PascalString(BytesInteger(4, swapped=True), "utf8"),
 )
 
@@ -172,6 +374,10 @@ def parse_member_account(member: PublicKey):
     data = parse_account(member)
     return member_account_schema.parse(b64decode(data))
 
+def parse_signature_account(signature: PublicKey):
+    data = parse_account(signature)
+    return signature_account_schema.parse(b64decode(data))
+
 def parse_admin_account(admin: PublicKey):
     data = parse_account(admin)
     return admin_account_schema.parse(b64decode(data))
@@ -1,37 +1,89 @@
 mod add_admin;
+mod cancel_proposal;
+mod change_vote;
+mod close_join_request;
 mod close_proposal;
 mod close_signature;
+mod commit_vote;
+mod configure_group;
+mod configure_membership;
+mod create_bond_escrow;
+mod create_election;
 mod create_group;
+mod create_instruction_buffer;
+mod create_invite;
+mod create_stake_escrow;
+mod create_vote_escrow;
 mod deposit_token;
+mod execute_proposal;
 mod exit_group;
+mod finalize_proposal;
 mod freeze_group;
 mod initialize;
 mod join_group;
+mod migrate_group;
+mod migrate_member;
 mod remove_admin;
+mod retract_vote;
+mod revoke_delegation;
 mod revoke_proposal;
+mod review_join_request;
+mod reveal_vote;
+mod set_council;
+mod set_delegation;
+mod settle_bond;
 mod sign_proposal;
+mod sponsor_proposal;
 mod submit_proposal;
 mod thaw_group;
+mod update_metadata;
 mod update_proposal;
 mod upgrade_group;
+mod veto_proposal;
 mod reset_group;
 mod reset_member;
 
 pub use add_admin::*;
+pub use cancel_proposal::*;
+pub use change_vote::*;
+pub use close_join_request::*;
 pub use close_proposal::*;
 pub use close_signature::*;
+pub use commit_vote::*;
+pub use configure_group::*;
+pub use configure_membership::*;
+pub use create_bond_escrow::*;
+pub use create_election::*;
 pub use create_group::*;
+pub use create_instruction_buffer::*;
+pub use create_invite::*;
+pub use create_stake_escrow::*;
+pub use create_vote_escrow::*;
 pub use deposit_token::*;
+pub use execute_proposal::*;
 pub use exit_group::*;
+pub use finalize_proposal::*;
 pub use freeze_group::*;
 pub use initialize::*;
 pub use join_group::*;
+pub use migrate_group::*;
+pub use migrate_member::*;
 pub use remove_admin::*;
+pub use retract_vote::*;
+pub use revoke_delegation::*;
 pub use revoke_proposal::*;
+pub use review_join_request::*;
+pub use reveal_vote::*;
+pub use set_council::*;
+pub use set_delegation::*;
+pub use settle_bond::*;
 pub use sign_proposal::*;
+pub use sponsor_proposal::*;
 pub use submit_proposal::*;
 pub use thaw_group::*;
+pub use update_metadata::*;
 pub use update_proposal::*;
 pub use upgrade_group::*;
+pub use veto_proposal::*;
 pub use reset_group::*;
 pub use reset_member::*;
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = admin.initialized @ GroupError::NotInitialized,
+    )]
+    pub admin: Account<'info, AdminAccount>,
+    /// CHECK: member account of the beneficiary, checked in handler as Remove closes it
+    #[account(mut)]
+    pub bene_member: UncheckedAccount<'info>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        constraint = group.load()?.admin == admin.key() @ GroupError::MismatchedGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_progressing() @ GroupError::ProposalAlreadyFinished,
+        constraint = !proposal.proposal_type.has_ballot() @ GroupError::InvalidBallot,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        constraint = signature.signer == authority.key() @ GroupError::MismatchedSigner,
+        constraint = signature.proposal == proposal.key() @ GroupError::MismatchedProposal,
+        constraint = signature.is_revealed() @ GroupError::VoteNotRevealed,
+    )]
+    pub signature: Box<Account<'info, SignatureAccount>>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseJoinRequest<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: refunded the join request rent
+    #[account(mut,
+        constraint = funder.key() == join_request.funder @ GroupError::MismatchedFunder,
+    )]
+    pub funder: AccountInfo<'info>,
+    #[account(mut,
+        close = funder,
+        constraint = join_request.decision.is_some() @ GroupError::JoinRequestPending,
+    )]
+    pub join_request: Account<'info, JoinRequestAccount>,
+}
@@ -6,14 +6,16 @@ pub struct CloseProposal<'info> {
     pub authority: Signer<'info>,
     /// CHECK:
     pub beneficiary: AccountInfo<'info>,
-    #[account(mut,
-        constraint = bene_member.group == group.key(),
-        constraint = bene_member.owner == beneficiary.key() @ GroupError::OperationUnauthorized,
-    )]
-    pub bene_member: Account<'info, MemberAccount>,
+    /// CHECK: member account of the beneficiary, checked in handler as Remove closes it
+    #[account(mut)]
+    pub bene_member: UncheckedAccount<'info>,
     #[account(mut,
         close = authority,
         constraint = proposal.submitter == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.beneficiary == beneficiary.key() @ GroupError::OperationUnauthorized,
+        constraint = proposal.escrowed == 0 @ GroupError::EscrowNotEmpty,
+        constraint = proposal.bond == 0 @ GroupError::BondNotSettled,
     )]
     pub proposal: Account<'info, ProposalAccount>,
     #[account(
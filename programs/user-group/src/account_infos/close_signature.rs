@@ -8,15 +8,31 @@ pub struct CloseSignature<'info> {
         constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
     )]
     pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(mut,
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // governance token, burned when forfeiting
     pub member: Account<'info, MemberAccount>,
     /// CHECK: only for check whether it is closed
+    #[account(mut)]
     pub proposal: AccountInfo<'info>,
+    /// CHECK: receives the signature rent and escrow refund
+    #[account(mut)]
+    pub signer: UncheckedAccount<'info>,
     #[account(mut,
-        close = authority,
-        constraint = signature.signer == authority.key() @ GroupError::MismatchedSigner,
+        close = signer,
+        constraint = signature.signer == signer.key() @ GroupError::MismatchedSigner,
         constraint = signature.proposal == proposal.key() @ GroupError::MismatchedProposal,
     )]
     pub signature: Account<'info, SignatureAccount>,
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
+    // Escrow
+    // proposal_escrow_token_info isWritable: true
+    // signer_token_account_info isWritable: true, unused when forfeiting an unrevealed commitment
+    // token_program_info
 }
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32], amount: u64)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(mut,
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // burning token
+    #[account(mut)]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = token.mint == mint.key(),
+        constraint = token.owner == authority.key(),
+        constraint = token.amount >= amount @ GroupError::InsufficientTokenBalance,
+    )]
+    pub token: Account<'info, TokenAccount>, // authority's burning token account
+    #[account(mut,
+        constraint = vault.mint == mint.key() @ GroupError::MismatchedToken,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>, // proposal escrow or group vault, unused when burning
+    #[account(
+        constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = member.group == group.key() @ GroupError::VoterNotInGroup,
+        constraint = proposal.proposal_type.is_voter(&member.position) @ GroupError::PositionNotEligible,
+        constraint = member.joined_at <= proposal.snapshot_at @ GroupError::JoinedAfterSnapshot,
+        constraint = proposal.counts_position_of(&member) @ GroupError::PositionChangedAfterSnapshot,
+    )]
+    pub member: Box<Account<'info, MemberAccount>>,
+    #[account(init,
+        seeds = [group.key().as_ref(), member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + SignatureAccount::LEN,
+    )]
+    pub signature: Box<Account<'info, SignatureAccount>>,
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_progressing() @ GroupError::ProposalAlreadyFinished,
+        constraint = proposal.payload_ready @ GroupError::PayloadNotReady,
+        constraint = proposal.is_commit_reveal() @ GroupError::WrongVotingMode,
+        constraint = !proposal.proposal_type.has_ballot() @ GroupError::WrongVotingMode,
+        constraint = amount >= proposal.limit @ GroupError::InsufficientVoteAmount,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> CommitVote<'info> {
+    pub fn as_token_burn_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.token.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn as_token_lock_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.token.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateBondEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // governance token
+    /// CHECK: the next proposal of the group, the bond is taken when it is submitted
+    #[account(
+        seeds = [group.key().as_ref(), &group.load()?.proposals.to_le_bytes(), ProposalAccount::SEEDS],
+        bump,
+    )]
+    pub proposal: UncheckedAccount<'info>,
+    #[account(init,
+        seeds = [proposal.key().as_ref(), ProposalAccount::BOND_ESCROW_SEEDS],
+        bump,
+        payer = authority,
+        token::mint = mint,
+        token::authority = proposal,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(candidates: Vec<Pubkey>)]
+pub struct CreateElection<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.submitter == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = proposal.participated() == 0 @ GroupError::ProposalAlreadySigned,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(init,
+        seeds = [proposal.key().as_ref(), ElectionAccount::SEEDS],
+        bump,
+        payer = authority,
+        space = 8 + ElectionAccount::space(candidates.len()),
+    )]
+    pub election: Box<Account<'info, ElectionAccount>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    // candidate_member_info, in the order of `candidates`
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(instructions: Vec<SerializedInstruction>)]
+pub struct CreateInstructionBuffer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut,
+        constraint = proposal.submitter == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = proposal.participated() == 0 @ GroupError::ProposalAlreadySigned,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(init,
+        seeds = [proposal.key().as_ref(), InstructionBufferAccount::SEEDS],
+        bump,
+        payer = authority,
+        space = 8 + InstructionBufferAccount::space(&instructions),
+    )]
+    pub buffer: Box<Account<'info, InstructionBufferAccount>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
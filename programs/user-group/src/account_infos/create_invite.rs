@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(code_hash: [u8; 32], max_uses: u32, expire_at: i64)]
+pub struct CreateInvite<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = manager.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = manager.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = manager.position.is_manager() @ GroupError::OperationUnauthorized,
+    )]
+    pub manager: Box<Account<'info, MemberAccount>>,
+    #[account(init,
+        seeds = [group.key().as_ref(), code_hash.as_ref(), InviteAccount::SEEDS],
+        bump,
+        payer = authority,
+        space = 8 + InviteAccount::LEN,
+        constraint = max_uses > 0 && expire_at > clock.unix_timestamp @ GroupError::InvalidInvite,
+    )]
+    pub invite: Box<Account<'info, InviteAccount>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateStakeEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = mint.key() == group.load()?.membership.gate_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // gate token
+    #[account(init,
+        seeds = [group.key().as_ref(), mint.key().as_ref(), MembershipConfig::STAKE_SEEDS],
+        bump,
+        payer = authority,
+        token::mint = mint,
+        token::authority = group,
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
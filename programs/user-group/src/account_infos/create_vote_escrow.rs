@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateVoteEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // governance token
+    #[account(
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(init,
+        seeds = [proposal.key().as_ref(), ProposalAccount::VOTE_ESCROW_SEEDS],
+        bump,
+        payer = authority,
+        token::mint = mint,
+        token::authority = proposal,
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: member account of the beneficiary, checked in handler as Remove closes it
+    #[account(mut)]
+    pub bene_member: UncheckedAccount<'info>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.bene_member == bene_member.key() @ GroupError::MismatchedBeneMember,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    pub clock: Sysvar<'info, Clock>,
+    // Withdraw
+    // group_vault_token_info isWritable: true
+    // user_token_account_info isWritable: true
+    // Remove
+    // funder_info isWritable: true
+    // Remove, when the member staked
+    // stake_escrow_token_info isWritable: true
+    // owner_token_account_info isWritable: true
+    // token_program_info
+}
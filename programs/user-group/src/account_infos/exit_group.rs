@@ -11,10 +11,15 @@ pub struct ExitGroup<'info> {
         close = funder,
         constraint = member.funder == funder.key() @ GroupError::MismatchedFunder,
         constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = !member.in_promotion && !member.in_withdraw @ GroupError::AlreadyInProposal,
     )]
     pub member: Account<'info, MemberAccount>,
     #[account(mut)]
     pub group: AccountLoader<'info, GroupAccount>,
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
+    // Stake refund
+    // stake_escrow_token_info isWritable: true
+    // owner_token_account_info isWritable: true
+    // token_program_info
 }
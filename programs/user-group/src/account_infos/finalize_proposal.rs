@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: member account of the beneficiary, checked in handler as Remove closes it
+    #[account(mut)]
+    pub bene_member: UncheckedAccount<'info>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = proposal.voting_end() < clock.unix_timestamp
+            || proposal.is_draft_expired(clock.unix_timestamp) @ GroupError::ProposalNotOutdated,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    pub clock: Sysvar<'info, Clock>,
+}
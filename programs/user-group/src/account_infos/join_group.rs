@@ -1,20 +1,37 @@
 use crate::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(proof: Vec<[u8; 32]>, position: Option<Position>, invite_code: Option<Vec<u8>>)]
 pub struct JoinGroup<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     /// CHECK:
     pub user: AccountInfo<'info>,
-    #[account(mut)]
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+        constraint = group.load()?.membership.has_allowlist() || position.is_none() @ GroupError::NotInAllowlist,
+    )]
     pub group: AccountLoader<'info, GroupAccount>,
-    #[account(init, payer = authority, space = 8 + MemberAccount::LEN,
+    /// CHECK: created as the member account when the group is open
+    #[account(mut,
         seeds = [group.key().as_ref(), user.key().as_ref(), MemberAccount::SEEDS],
         bump,
-        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
     )]
-    pub member: Account<'info, MemberAccount>,
+    pub member: UncheckedAccount<'info>,
+    /// CHECK: created as the join request when the group requires review
+    #[account(mut,
+        seeds = [group.key().as_ref(), user.key().as_ref(), JoinRequestAccount::SEEDS],
+        bump,
+    )]
+    pub join_request: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     pub clock: Sysvar<'info, Clock>,
+    // Invite, when invite_code is given
+    // invite_info isWritable: true
+    // Token gate
+    // user_token_account_info isWritable: true
+    // Stake, user must sign
+    // stake_escrow_token_info isWritable: true
+    // token_program_info
 }
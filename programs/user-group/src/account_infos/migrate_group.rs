@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateGroup<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = admin.initialized @ GroupError::NotInitialized,
+    )]
+    pub admin: Account<'info, AdminAccount>,
+    /// CHECK: group created before vote and membership config, checked in handler
+    #[account(mut, owner = crate::ID)]
+    pub group: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    /// CHECK: member created before council, join time, stake and invites, checked in handler
+    #[account(mut, owner = crate::ID)]
+    pub member: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
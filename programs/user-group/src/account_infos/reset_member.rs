@@ -6,15 +6,13 @@ pub struct ResetMember<'info> {
     pub authority: Signer<'info>,
     /// CHECK: 
     pub beneficiary: UncheckedAccount<'info>,
-    #[account(mut,
-        constraint = bene_member.owner == beneficiary.key() @ GroupError::MismatchedBeneMember,
-        constraint = bene_member.in_promotion || bene_member.in_withdraw @ GroupError::MemberNotInProposal,
-    )]
-    pub bene_member: Account<'info, MemberAccount>,
+    /// CHECK: member account of the beneficiary, checked in handler as Remove closes it
+    #[account(mut)]
+    pub bene_member: UncheckedAccount<'info>,
     #[account(
         constraint = proposal.beneficiary == beneficiary.key() @ GroupError::MismatchedBeneMember,
-        constraint = proposal.bene_member == bene_member.key() @ GroupError::MismatchedBeneMember,
         constraint = proposal.deadline <= clock.unix_timestamp @ GroupError::ProposalNotOutdated,
+        constraint = proposal.is_finished() @ GroupError::ProposalNotFinished,
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
     pub clock: Sysvar<'info, Clock>,
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_progressing() @ GroupError::ProposalAlreadyFinished,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        close = authority,
+        constraint = signature.signer == authority.key() @ GroupError::MismatchedSigner,
+        constraint = signature.proposal == proposal.key() @ GroupError::MismatchedProposal,
+    )]
+    pub signature: Box<Account<'info, SignatureAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    // Escrow
+    // proposal_escrow_token_info isWritable: true
+    // user_token_account_info isWritable: true
+}
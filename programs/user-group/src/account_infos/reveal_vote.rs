@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_progressing() @ GroupError::ProposalAlreadyFinished,
+        constraint = proposal.is_commit_reveal() @ GroupError::WrongVotingMode,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        constraint = signature.signer == authority.key() @ GroupError::MismatchedSigner,
+        constraint = signature.proposal == proposal.key() @ GroupError::MismatchedProposal,
+    )]
+    pub signature: Box<Account<'info, SignatureAccount>>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReviewJoinRequest<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = reviewer.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = reviewer.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = reviewer.position.is_manager() @ GroupError::OperationUnauthorized,
+    )]
+    pub reviewer: Box<Account<'info, MemberAccount>>,
+    /// CHECK:
+    #[account(
+        constraint = user.key() == join_request.user @ GroupError::MismatchedBeneMember,
+    )]
+    pub user: AccountInfo<'info>,
+    #[account(mut,
+        constraint = join_request.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = join_request.decision.is_none() @ GroupError::JoinRequestDecided,
+    )]
+    pub join_request: Box<Account<'info, JoinRequestAccount>>,
+    #[account(init,
+        seeds = [join_request.key().as_ref(), reviewer.key().as_ref(), JoinReviewAccount::SEEDS],
+        bump,
+        payer = authority,
+        space = 8 + JoinReviewAccount::LEN,
+    )]
+    pub review: Box<Account<'info, JoinReviewAccount>>,
+    /// CHECK: created as the member account once approved
+    #[account(mut,
+        seeds = [group.key().as_ref(), user.key().as_ref(), MemberAccount::SEEDS],
+        bump,
+    )]
+    pub member: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    // Stake refund, when rejected
+    // stake_escrow_token_info isWritable: true
+    // user_token_account_info isWritable: true
+    // token_program_info
+}
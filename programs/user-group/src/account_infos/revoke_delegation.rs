@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub member: Box<Account<'info, MemberAccount>>,
+    #[account(mut,
+        close = authority,
+        seeds = [group.key().as_ref(), member.key().as_ref()],
+        bump,
+        constraint = delegation.owner == authority.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub delegation: Box<Account<'info, DelegationAccount>>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
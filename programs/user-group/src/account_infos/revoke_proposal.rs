@@ -15,6 +15,8 @@ pub struct RevokeProposal<'info> {
         constraint = proposal.submitter == authority.key() @ GroupError::OperationUnauthorized,
         constraint = proposal.beneficiary == beneficiary.key() @ GroupError::MismatchedBeneMember,
         constraint = proposal.bene_member == bene_member.key() @ GroupError::MismatchedBeneMember,
+        constraint = proposal.is_progressing() || proposal.is_draft() @ GroupError::ProposalAlreadyFinished,
+        constraint = proposal.escrowed == 0 @ GroupError::EscrowNotEmpty,
     )]
     pub proposal: Account<'info, ProposalAccount>,
     #[account(mut,
@@ -23,4 +25,8 @@ pub struct RevokeProposal<'info> {
     pub group: AccountLoader<'info, GroupAccount>,
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
+    // Bond
+    // submitter_token_account_info isWritable: true
+    // proposal_escrow_token_info isWritable: true
+    // token_program_info
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCouncil<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = admin.initialized @ GroupError::NotInitialized,
+    )]
+    pub admin: Account<'info, AdminAccount>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::MismatchedGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(mut,
+        constraint = member.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = member.position.is_manager() @ GroupError::AlreadyMember,
+    )]
+    pub member: Account<'info, MemberAccount>,
+}
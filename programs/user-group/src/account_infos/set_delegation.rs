@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDelegation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = member.group == group.key() @ GroupError::MismatchedGroup,
+    )]
+    pub member: Box<Account<'info, MemberAccount>>,
+    #[account(
+        constraint = delegate.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = delegate.key() != member.key() @ GroupError::InvalidDelegate,
+    )]
+    pub delegate: Box<Account<'info, MemberAccount>>,
+    #[account(init,
+        seeds = [group.key().as_ref(), member.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + DelegationAccount::LEN,
+    )]
+    pub delegation: Box<Account<'info, DelegationAccount>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
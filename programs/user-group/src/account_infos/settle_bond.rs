@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleBond<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(mut,
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
+    pub mint: Account<'info, Mint>, // governance token
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.bond > 0 @ GroupError::NoBond,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(mut,
+        seeds = [proposal.key().as_ref(), ProposalAccount::BOND_ESCROW_SEEDS],
+        bump,
+        constraint = escrow.owner == proposal.key() @ GroupError::WrongVaultTokenAccount,
+        constraint = escrow.mint == mint.key() @ GroupError::MismatchedToken,
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = submitter_token.owner == proposal.submitter @ GroupError::OperationUnauthorized,
+        constraint = submitter_token.mint == mint.key() @ GroupError::MismatchedToken,
+    )]
+    pub submitter_token: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = vault.owner == group.key() @ GroupError::WrongVaultTokenAccount,
+        constraint = vault.mint == mint.key() @ GroupError::MismatchedToken,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>, // unused unless slashed to treasury
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -1,23 +1,42 @@
 use crate::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(sign: SignType, amount: u64, choices: Vec<u8>)]
 pub struct SignProposal<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        constraint = group.load()?.admin == admin.key() @ GroupError::OperationUnauthorized,
+    )]
+    pub admin: Box<Account<'info, AdminAccount>>,
+    #[account(mut,
+        constraint = mint.key() == admin.token_mint @ GroupError::MismatchedToken,
+    )]
     pub mint: Account<'info, Mint>, // burning token
     #[account(mut)]
     pub group: AccountLoader<'info, GroupAccount>,
     #[account(mut,
         constraint = token.mint == mint.key(),
         constraint = token.owner == authority.key(),
-        constraint = token.amount >= proposal.limit @ GroupError::InsufficientTokenBalance,
+        constraint = token.amount >= amount @ GroupError::InsufficientTokenBalance,
     )]
     pub token: Account<'info, TokenAccount>, // authority's burning token account
+    #[account(mut,
+        constraint = vault.mint == mint.key() @ GroupError::MismatchedToken,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>, // proposal escrow or group vault, unused when burning
     #[account(
         constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = member.group == group.key() @ GroupError::VoterNotInGroup,
+        constraint = proposal.proposal_type.is_voter(&member.position) @ GroupError::PositionNotEligible,
+        constraint = member.joined_at <= proposal.snapshot_at @ GroupError::JoinedAfterSnapshot,
+        constraint = proposal.counts_position_of(&member) @ GroupError::PositionChangedAfterSnapshot,
     )]
     pub member: Box<Account<'info, MemberAccount>>,
+    #[account(mut,
+        constraint = proposal.bene_member == bene_member.key() @ GroupError::MismatchedBeneMember,
+    )]
+    pub bene_member: Box<Account<'info, MemberAccount>>,
     #[account(init,
         seeds = [group.key().as_ref(), member.key().as_ref(), proposal.key().as_ref()],
         bump,
@@ -27,19 +46,19 @@ pub struct SignProposal<'info> {
     pub signature: Box<Account<'info, SignatureAccount>>,
     #[account(mut,
         constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = !proposal.is_draft() @ GroupError::ProposalInDraft,
+        constraint = proposal.is_progressing() @ GroupError::ProposalAlreadyFinished,
+        constraint = proposal.payload_ready @ GroupError::PayloadNotReady,
+        constraint = !proposal.is_commit_reveal() @ GroupError::WrongVotingMode,
+        constraint = amount >= proposal.limit @ GroupError::InsufficientVoteAmount,
+        constraint = proposal.proposal_type.is_valid_ballot(&sign, &choices) @ GroupError::InvalidBallot,
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
     pub clock: Sysvar<'info, Clock>,
-    // Upgrade | Downgrade | ReElection
-    // bene_member_info  isWritable: true
-
-    // Withdraw
-    // bene_member_info  isWritable: true
-    // group_vault_token_info isWritable: true
-    // user_token_account_info isWritable: true
 }
 
 impl<'info> SignProposal<'info> {
@@ -52,4 +71,14 @@ impl<'info> SignProposal<'info> {
         let cpi_program = self.token_program.to_account_info();
         CpiContext::new(cpi_program, cpi_accounts)
     }
+
+    pub fn as_token_lock_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.token.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
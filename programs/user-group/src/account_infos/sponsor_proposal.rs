@@ -0,0 +1,33 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct SponsorProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+    )]
+    pub group: AccountLoader<'info, GroupAccount>,
+    #[account(
+        constraint = member.owner == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = member.group == group.key() @ GroupError::MismatchedGroup,
+        constraint = member.owner != proposal.submitter @ GroupError::InvalidSponsor,
+    )]
+    pub member: Box<Account<'info, MemberAccount>>,
+    #[account(mut,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_draft() @ GroupError::ProposalNotDraft,
+        constraint = proposal.payload_ready @ GroupError::PayloadNotReady,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    #[account(init,
+        seeds = [proposal.key().as_ref(), member.key().as_ref(), SponsorshipAccount::SEEDS],
+        bump,
+        payer = authority,
+        space = 8 + SponsorshipAccount::LEN,
+    )]
+    pub sponsorship: Box<Account<'info, SponsorshipAccount>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -32,4 +32,9 @@ pub struct SubmitProposal<'info> {
     pub clock: Sysvar<'info, Clock>,
     // Withdraw
     // group_vault_token_account_info
+    // Bond, when required by group config
+    // admin_info
+    // submitter_token_account_info isWritable: true
+    // proposal_escrow_token_info isWritable: true
+    // token_program_info
 }
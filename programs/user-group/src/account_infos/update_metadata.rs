@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut,
+        constraint = !group.load()?.freeze @ GroupError::FrozenGroup,
+        constraint = proposal.submitter == authority.key() @ GroupError::OperationUnauthorized,
+        constraint = proposal.group == group.key() @ GroupError::ProposalNotInGroup,
+        constraint = proposal.is_progressing() || proposal.is_draft() @ GroupError::ProposalAlreadyFinished,
+        constraint = !proposal.voted @ GroupError::ProposalAlreadySigned,
+        // sponsors backed the current metadata
+        constraint = proposal.sponsors == 0 @ GroupError::ProposalAlreadySponsored,
+        // commitments are not counted until revealed
+        constraint = !proposal.is_commit_reveal() @ GroupError::WrongVotingMode,
+    )]
+    pub proposal: Box<Account<'info, ProposalAccount>>,
+    pub group: AccountLoader<'info, GroupAccount>,
+    pub clock: Sysvar<'info, Clock>,
+}
@@ -80,4 +80,84 @@ pub enum GroupError {
     ProposalNotOutdated,
     #[msg("Member is not in any proposal")]
     MemberNotInProposal,
+    #[msg("Proposal is already finished")]
+    ProposalAlreadyFinished,
+    #[msg("Vote amount is less than the proposal limit")]
+    InsufficientVoteAmount,
+    #[msg("Invalid vote config")]
+    InvalidVoteConfig,
+    #[msg("Escrowed token is not fully refunded")]
+    EscrowNotEmpty,
+    #[msg("Member joined after the proposal was submitted")]
+    JoinedAfterSnapshot,
+    #[msg("Member does not belong to the group")]
+    VoterNotInGroup,
+    #[msg("Proposal does not belong to the group")]
+    ProposalNotInGroup,
+    #[msg("Member position is not eligible to vote on this proposal")]
+    PositionNotEligible,
+    #[msg("Proposal is not queued for execution")]
+    ProposalNotQueued,
+    #[msg("Proposal timelock has not elapsed")]
+    ProposalNotReady,
+    #[msg("Proposal is already signed")]
+    ProposalAlreadySigned,
+    #[msg("Invalid instruction buffer")]
+    InvalidInstructionBuffer,
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+    #[msg("Mismatched delegation")]
+    MismatchedDelegation,
+    #[msg("Wrong voting mode for proposal")]
+    WrongVotingMode,
+    #[msg("Invalid vote commitment")]
+    InvalidCommitment,
+    #[msg("Vote is not revealed")]
+    VoteNotRevealed,
+    #[msg("Invalid ballot")]
+    InvalidBallot,
+    #[msg("Invalid election")]
+    InvalidElection,
+    #[msg("Invalid signal options")]
+    InvalidSignal,
+    #[msg("Proposal title or uri is too long")]
+    MetadataTooLong,
+    #[msg("Proposal has no bond to settle")]
+    NoBond,
+    #[msg("Proposal bond is not settled")]
+    BondNotSettled,
+    #[msg("Proposal is not finished")]
+    ProposalNotFinished,
+    #[msg("Only queued proposals can be vetoed")]
+    ProposalNotVetoable,
+    #[msg("Proposal is not a draft")]
+    ProposalNotDraft,
+    #[msg("Proposal is a draft waiting for sponsors")]
+    ProposalInDraft,
+    #[msg("Submitter can not sponsor its own proposal")]
+    InvalidSponsor,
+    #[msg("Invalid membership config")]
+    InvalidMembershipConfig,
+    #[msg("User is not in the group allowlist")]
+    NotInAllowlist,
+    #[msg("Invite is invalid or used up")]
+    InvalidInvite,
+    #[msg("Invite is expired")]
+    InviteExpired,
+    #[msg("Proposal instruction buffer or election is not created")]
+    PayloadNotReady,
+    #[msg("Group is already migrated")]
+    GroupAlreadyMigrated,
+    #[msg("Join request is already decided")]
+    JoinRequestDecided,
+    #[msg("Join request is not decided yet")]
+    JoinRequestPending,
+    #[msg("Member is already migrated")]
+    MemberAlreadyMigrated,
+    #[msg("Member position changed after the proposal was submitted")]
+    PositionChangedAfterSnapshot,
+    #[msg("Proposal is already sponsored")]
+    ProposalAlreadySponsored,
+    #[msg("Gate mint can not change while stakes are held")]
+    StakesOutstanding,
 }
\ No newline at end of file
@@ -11,12 +11,91 @@ pub struct JoinGroupEvent {
     pub label: String,
 }
 
+#[event]
+pub struct CreateInviteEvent {
+    pub group: Pubkey,
+    pub invite: Pubkey,
+    pub manager: Pubkey,
+    pub creator: Pubkey,
+    pub max_uses: u32,
+    pub expire_at: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct JoinRequestEvent {
+    pub user: Pubkey,
+    pub group: Pubkey,
+    pub request: Pubkey,
+    pub funder: Pubkey,
+    pub time: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct ReviewJoinRequestEvent {
+    pub user: Pubkey,
+    pub group: Pubkey,
+    pub request: Pubkey,
+    pub reviewer: Pubkey,
+    pub approve: bool,
+    pub approvals: u32,
+    pub rejections: u32,
+    pub decided: Option<bool>,
+    pub time: i64,
+    #[index]
+    pub label: String,
+}
+
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum ExitReason {
+    Voluntary,
+    Removed,
+}
+
 #[event]
 pub struct ExitGroupEvent {
     pub user: Pubkey,
     pub group: Pubkey,
     pub member: Pubkey,
     pub funder: Pubkey,
+    pub reason: ExitReason,
+    pub time: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct MigrateMemberEvent {
+    pub authority: Pubkey,
+    pub group: Pubkey,
+    pub member: Pubkey,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct SetDelegationEvent {
+    pub owner: Pubkey,
+    pub group: Pubkey,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub delegation: Pubkey,
+    pub time: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct RevokeDelegationEvent {
+    pub owner: Pubkey,
+    pub group: Pubkey,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub delegation: Pubkey,
     pub time: i64,
     #[index]
     pub label: String,
@@ -31,6 +110,15 @@ pub struct FreezeGroupEvent {
     pub label: String,
 }
 
+#[event]
+pub struct MigrateGroupEvent {
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub group: Pubkey,
+    #[index]
+    pub label: String,
+}
+
 #[event]
 pub struct ThawGroupEvent {
     pub authority: Pubkey,
@@ -50,6 +138,26 @@ pub struct UpgradeGroupEvent {
     pub label: String,
 }
 
+#[event]
+pub struct ConfigureGroupEvent {
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub group: Pubkey,
+    pub config: VoteConfig,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct ConfigureMembershipEvent {
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub group: Pubkey,
+    pub membership: MembershipConfig,
+    #[index]
+    pub label: String,
+}
+
 #[repr(u8)]
 #[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
 pub enum ProposalEventType {
@@ -58,6 +166,10 @@ pub enum ProposalEventType {
     Withdraw,
     UpdateGroup,
     ReElection,
+    Execute,
+    Election,
+    Signal,
+    Remove,
 }
 impl From<ProposalType> for ProposalEventType {
     fn from(proposal_type: ProposalType) -> Self {
@@ -67,6 +179,10 @@ impl From<ProposalType> for ProposalEventType {
             ProposalType::Withdraw { .. } => ProposalEventType::Withdraw,
             ProposalType::UpdateGroup { .. } => ProposalEventType::UpdateGroup,
             ProposalType::ReElection => ProposalEventType::ReElection,
+            ProposalType::Execute { .. } => ProposalEventType::Execute,
+            ProposalType::Election { .. } => ProposalEventType::Election,
+            ProposalType::Signal { .. } => ProposalEventType::Signal,
+            ProposalType::Remove => ProposalEventType::Remove,
         }
     }
 }
@@ -81,6 +197,32 @@ pub struct SubmitProposalEvent {
     pub group: Pubkey,
     pub proposal: Pubkey,
     pub deadline: i64,
+    pub title: String,
+    pub uri: String,
+    pub content_hash: [u8; 32],
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct SettleBondEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub submitter: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct UpdateMetadataEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub submitter: Pubkey,
+    pub title: String,
+    pub uri: String,
+    pub content_hash: [u8; 32],
     #[index]
     pub label: String,
 }
@@ -93,7 +235,50 @@ pub struct SignProposalEvent {
     pub group: Pubkey,
     pub proposal: Pubkey,
     pub stype: SignType,
+    pub amount: u64,
+    pub weight: u64,
     pub signer: Pubkey,
+    pub member: Pubkey,
+    pub signature: Pubkey,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct CommitVoteEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub commitment: [u8; 32],
+    pub amount: u64,
+    pub signer: Pubkey,
+    pub member: Pubkey,
+    pub signature: Pubkey,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct ChangeVoteEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub from: SignType,
+    pub to: SignType,
+    pub amount: u64,
+    pub signer: Pubkey,
+    pub member: Pubkey,
+    pub signature: Pubkey,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct RetractVoteEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub stype: SignType,
+    pub amount: u64,
+    pub signer: Pubkey,
+    pub member: Pubkey,
     pub signature: Pubkey,
     #[index]
     pub label: String,
@@ -104,6 +289,22 @@ pub struct SignProposalEvent {
 pub enum ProposalResult {
     Passed,
     Rejected,
+    Expired,
+    Queued,
+    Canceled,
+}
+
+#[event]
+pub struct SignalResultEvent {
+    pub submitter: Pubkey,
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub metadata: [u8; 32],
+    pub options: u8,
+    pub counts: [u64; MAX_CHOICES],
+    pub time: i64,
+    #[index]
+    pub label: String,
 }
 
 #[event]
@@ -118,6 +319,41 @@ pub struct ExecuteProposalEvent {
     pub label: String,
 }
 
+#[event]
+pub struct SponsorProposalEvent {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub sponsor: Pubkey,
+    pub member: Pubkey,
+    pub sponsors: u16,
+    pub opened: bool,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct VetoProposalEvent {
+    pub ptype: ProposalEventType,
+    pub submitter: Pubkey,
+    pub beneficiary: Pubkey,
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    pub by: Pubkey,
+    pub time: i64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct SetCouncilEvent {
+    pub authority: Pubkey,
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub council: bool,
+    #[index]
+    pub label: String,
+}
+
 #[event]
 pub struct DepositTokenEvent {
     pub user: Pubkey,
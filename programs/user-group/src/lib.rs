@@ -96,6 +96,7 @@ pub mod user_group {
         group.sponsor = ctx.accounts.sponsor.key().clone();
         group.admin = ctx.accounts.admin.key().clone();
         group.rate = ParticipateRate::new(100, 100);
+        group.config = VoteConfig::default();
 
         let admin = &mut ctx.accounts.admin;
         admin.groups += 1;
@@ -131,6 +132,65 @@ pub mod user_group {
         Ok(())
     }
 
+    // grow a group created before vote and membership config, the authority pays the rent
+    #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
+    pub fn migrate_group(ctx: Context<MigrateGroup>) -> Result<()> {
+        let group_info = ctx.accounts.group.to_account_info();
+        let space = 8 + GroupAccount::LEN;
+        if group_info.data_len() >= space {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::GroupAlreadyMigrated)
+            } else {
+                Err(GroupError::GroupAlreadyMigrated.into())
+            };
+        }
+
+        let required = ctx.accounts.rent.minimum_balance(space);
+        if group_info.lamports() < required {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: group_info.clone(),
+                    },
+                ),
+                required - group_info.lamports(),
+            )?;
+        }
+        // zero-initialized, so the layout padding is cleared
+        group_info.realloc(space, true)?;
+
+        // checks owner and discriminator of the grown account
+        let loader = AccountLoader::<GroupAccount>::try_from(&group_info)?;
+        let group = &mut loader.load_mut()?;
+        let seeds = &[
+            group.admin.as_ref(),
+            &group.index.to_le_bytes(),
+            GroupAccount::SEEDS,
+            &[group.seed],
+        ];
+        if group.admin != ctx.accounts.admin.key()
+            || Pubkey::create_program_address(seeds, ctx.program_id) != Ok(group_info.key())
+        {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MismatchedGroup)
+            } else {
+                Err(GroupError::MismatchedGroup.into())
+            };
+        }
+        group.config = VoteConfig::default();
+        group.membership = MembershipConfig::default();
+
+        emit!(MigrateGroupEvent {
+            authority: ctx.accounts.authority.key(),
+            admin: ctx.accounts.admin.key(),
+            group: group_info.key(),
+            label: "Migrated".to_string(),
+        });
+        Ok(())
+    }
+
     #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
     pub fn upgrade_group(ctx: Context<UpgradeGroup>, rate: ParticipateRate) -> Result<()> {
         let group = &mut ctx.accounts.group.load_mut()?;
@@ -146,37 +206,450 @@ pub mod user_group {
         Ok(())
     }
 
-    // user
-    pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
+    #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
+    pub fn configure_group(ctx: Context<ConfigureGroup>, config: VoteConfig) -> Result<()> {
+        if !config.is_valid() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::InvalidVoteConfig)
+            } else {
+                Err(GroupError::InvalidVoteConfig.into())
+            };
+        }
         let group = &mut ctx.accounts.group.load_mut()?;
+        group.config = config;
+
+        emit!(ConfigureGroupEvent {
+            authority: ctx.accounts.authority.key(),
+            admin: ctx.accounts.admin.key(),
+            group: ctx.accounts.group.key(),
+            config,
+            label: "Configured".to_string(),
+        });
+        Ok(())
+    }
+
+    #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
+    pub fn set_council(ctx: Context<SetCouncil>, council: bool) -> Result<()> {
         let member = &mut ctx.accounts.member;
-        member.group = ctx.accounts.group.key().clone();
-        member.in_promotion = false;
-        member.in_withdraw = false;
-        member.owner = ctx.accounts.user.key().clone();
-        member.funder = ctx.accounts.authority.key().clone();
-
-        if group.current_member == 0 && group.current_manager == 0 {
-            member.position = Position::Manager;
-            group.current_manager += 1;
+        member.council = council;
+
+        emit!(SetCouncilEvent {
+            authority: ctx.accounts.authority.key(),
+            group: ctx.accounts.group.key(),
+            member: member.key(),
+            council,
+            label: "SetCouncil".to_string(),
+        });
+        Ok(())
+    }
+
+    #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
+    pub fn configure_membership(
+        ctx: Context<ConfigureMembership>,
+        membership: MembershipConfig,
+    ) -> Result<()> {
+        if !membership.is_valid() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::InvalidMembershipConfig)
+            } else {
+                Err(GroupError::InvalidMembershipConfig.into())
+            };
+        }
+        let group = &mut ctx.accounts.group.load_mut()?;
+        // stakes are refunded from the escrow of their own mint, keep it while any is held
+        if group.stakes > 0 && membership.gate_mint != group.membership.gate_mint {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::StakesOutstanding)
+            } else {
+                Err(GroupError::StakesOutstanding.into())
+            };
+        }
+        group.membership = membership;
+
+        emit!(ConfigureMembershipEvent {
+            authority: ctx.accounts.authority.key(),
+            admin: ctx.accounts.admin.key(),
+            group: ctx.accounts.group.key(),
+            membership,
+            label: "ConfiguredMembership".to_string(),
+        });
+        Ok(())
+    }
+
+    // token account of the group escrowing stakes of the gate mint
+    pub fn create_stake_escrow(_ctx: Context<CreateStakeEscrow>) -> Result<()> {
+        Ok(())
+    }
+
+    // user
+    pub fn join_group<'info>(
+        ctx: Context<'_, '_, '_, 'info, JoinGroup<'info>>,
+        proof: Vec<[u8; 32]>,
+        position: Option<Position>,
+        invite_code: Option<Vec<u8>>,
+    ) -> Result<()> {
+        let group_key = ctx.accounts.group.key();
+        let user_key = ctx.accounts.user.key();
+        let now = ctx.accounts.clock.unix_timestamp;
+        let group = &mut ctx.accounts.group.load_mut()?;
+        let account_iter = &mut ctx.remaining_accounts.iter();
+        // lamports alone may have been sent by anyone, only a program owned account is a member
+        if ctx.accounts.member.owner == ctx.program_id {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::AlreadyMember)
+            } else {
+                Err(GroupError::AlreadyMember.into())
+            };
+        }
+
+        let (invite, inviter) = match invite_code {
+            Some(code) => {
+                let invite_info = next_account_info(account_iter)?;
+                let mut invite = Account::<InviteAccount>::try_from(invite_info)?;
+                if invite.group != group_key || !invite.is_code(&code) || !invite.redeem() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidInvite)
+                    } else {
+                        Err(GroupError::InvalidInvite.into())
+                    };
+                }
+                if now > invite.expire_at {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InviteExpired)
+                    } else {
+                        Err(GroupError::InviteExpired.into())
+                    };
+                }
+                invite.exit(ctx.program_id)?;
+                (invite.key(), invite.manager)
+            }
+            None => (Pubkey::default(), Pubkey::default()),
+        };
+        let invited = invite != Pubkey::default();
+
+        let membership = group.membership;
+        // an invite stands in for the allowlist, a preassigned position still needs the proof
+        let allowlisted = membership.has_allowlist() && (position.is_some() || !invited);
+        if allowlisted && !membership.is_allowlisted(&user_key, &position, &proof) {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::NotInAllowlist)
+            } else {
+                Err(GroupError::NotInAllowlist.into())
+            };
+        }
+        if position == Some(Position::Manager) && group.current_manager >= group.max_manager {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::UpdateFirst)
+            } else {
+                Err(GroupError::UpdateFirst.into())
+            };
+        }
+        let staked = if membership.gate == TokenGate::None {
+            0
+        } else {
+            let user_token_account_info = next_account_info(account_iter)?;
+            let user_token = Account::<TokenAccount>::try_from(user_token_account_info)?;
+            if user_token.mint != membership.gate_mint {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::MismatchedToken)
+                } else {
+                    Err(GroupError::MismatchedToken.into())
+                };
+            }
+            if user_token.owner != user_key {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::OperationUnauthorized)
+                } else {
+                    Err(GroupError::OperationUnauthorized.into())
+                };
+            }
+            if user_token.amount < membership.gate_amount {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::InsufficientTokenBalance)
+                } else {
+                    Err(GroupError::InsufficientTokenBalance.into())
+                };
+            }
+
+            if membership.gate == TokenGate::Stake {
+                let stake_escrow_token_info = next_account_info(account_iter)?;
+                let token_program_info = next_account_info(account_iter)?;
+                if token_program_info.key() != token::ID {
+                    return Err(ProgramError::IncorrectProgramId.into());
+                }
+                is_stake_escrow(
+                    &group_key,
+                    &membership.gate_mint,
+                    stake_escrow_token_info,
+                    ctx.program_id,
+                )?;
+                if !ctx.accounts.user.is_signer {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::OperationUnauthorized)
+                    } else {
+                        Err(GroupError::OperationUnauthorized.into())
+                    };
+                }
+                token::transfer(
+                    CpiContext::new(
+                        token_program_info.clone(),
+                        Transfer {
+                            from: user_token_account_info.clone(),
+                            to: stake_escrow_token_info.clone(),
+                            authority: ctx.accounts.user.to_account_info(),
+                        },
+                    ),
+                    membership.gate_amount,
+                )?;
+                group.stakes += 1;
+                membership.gate_amount
+            } else {
+                0
+            }
+        };
+        let stake_mint = if staked > 0 {
+            membership.gate_mint
         } else {
-            member.position = Position::Member;
-            group.current_member += 1;
+            Pubkey::default()
+        };
+        // the first member joins directly to bootstrap the managers,
+        // allowlisted and invited users are already approved
+        let open = invited
+            || membership.has_allowlist()
+            || membership.policy == JoinPolicy::Open
+            || (group.current_member == 0 && group.current_manager == 0);
+
+        if open {
+            let bump = *ctx.bumps.get("member").unwrap();
+            let seeds = &[group_key.as_ref(), user_key.as_ref(), MemberAccount::SEEDS, &[bump]];
+            create_pda_account(
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.member.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.rent,
+                8 + MemberAccount::LEN,
+                seeds,
+                ctx.program_id,
+            )?;
+            let member = MemberAccount {
+                position: group.admit(position),
+                in_promotion: false,
+                in_withdraw: false,
+                council: false,
+                group: group_key,
+                funder: ctx.accounts.authority.key(),
+                owner: user_key,
+                joined_at: now,
+                positioned_at: now,
+                staked,
+                stake_mint,
+                invite,
+                inviter,
+            };
+            member.try_serialize(&mut &mut ctx.accounts.member.try_borrow_mut_data()?[..])?;
+
+            emit!(JoinGroupEvent {
+                user: user_key,
+                group: group_key,
+                member: ctx.accounts.member.key(),
+                funder: member.funder,
+                time: now,
+                label: "JoinGroup".to_string(),
+            });
+            return Ok(());
         }
 
-        emit!(JoinGroupEvent {
-            user: ctx.accounts.user.key().clone(),
-            group: member.group.clone(),
-            member: member.key().clone(),
-            funder: ctx.accounts.authority.key().clone(),
-            time: ctx.accounts.clock.unix_timestamp,
-            label: "JoinGroup".to_string(),
+        let bump = *ctx.bumps.get("join_request").unwrap();
+        let seeds = &[group_key.as_ref(), user_key.as_ref(), JoinRequestAccount::SEEDS, &[bump]];
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.join_request.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent,
+            8 + JoinRequestAccount::LEN,
+            seeds,
+            ctx.program_id,
+        )?;
+        let request = JoinRequestAccount {
+            group: group_key,
+            user: user_key,
+            funder: ctx.accounts.authority.key(),
+            created_at: now,
+            approvals: 0,
+            rejections: 0,
+            staked,
+            stake_mint,
+            decision: None,
+        };
+        request.try_serialize(&mut &mut ctx.accounts.join_request.try_borrow_mut_data()?[..])?;
+
+        emit!(JoinRequestEvent {
+            user: user_key,
+            group: group_key,
+            request: ctx.accounts.join_request.key(),
+            funder: request.funder,
+            time: now,
+            label: "JoinRequest".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn create_invite(
+        ctx: Context<CreateInvite>,
+        code_hash: [u8; 32],
+        max_uses: u32,
+        expire_at: i64,
+    ) -> Result<()> {
+        let invite = &mut ctx.accounts.invite;
+        invite.group = ctx.accounts.group.key();
+        invite.manager = ctx.accounts.manager.key();
+        invite.creator = ctx.accounts.authority.key();
+        invite.code_hash = code_hash;
+        invite.remaining_uses = max_uses;
+        invite.expire_at = expire_at;
+        invite.created_at = ctx.accounts.clock.unix_timestamp;
+
+        emit!(CreateInviteEvent {
+            group: invite.group,
+            invite: invite.key(),
+            manager: invite.manager,
+            creator: invite.creator,
+            max_uses,
+            expire_at,
+            label: "CreateInvite".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn review_join_request<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReviewJoinRequest<'info>>,
+        approve: bool,
+    ) -> Result<()> {
+        let group_key = ctx.accounts.group.key();
+        let user_key = ctx.accounts.user.key();
+        let now = ctx.accounts.clock.unix_timestamp;
+        let group = &mut ctx.accounts.group.load_mut()?;
+
+        let review = &mut ctx.accounts.review;
+        review.request = ctx.accounts.join_request.key();
+        review.reviewer = ctx.accounts.reviewer.key();
+        review.created_at = now;
+        review.approve = approve;
+
+        let request = &mut ctx.accounts.join_request;
+        if approve {
+            request.approvals += 1;
+        } else {
+            request.rejections += 1;
+        }
+        let membership = &group.membership;
+        let decided = request.decide(membership.policy, &membership.threshold, group.manager_number());
+
+        emit!(ReviewJoinRequestEvent {
+            user: user_key,
+            group: group_key,
+            request: request.key(),
+            reviewer: ctx.accounts.reviewer.key(),
+            approve,
+            approvals: request.approvals,
+            rejections: request.rejections,
+            decided,
+            time: now,
+            label: "ReviewJoinRequest".to_string(),
         });
 
+        if decided == Some(true) {
+            let bump = *ctx.bumps.get("member").unwrap();
+            let seeds = &[group_key.as_ref(), user_key.as_ref(), MemberAccount::SEEDS, &[bump]];
+            create_pda_account(
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.member.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.rent,
+                8 + MemberAccount::LEN,
+                seeds,
+                ctx.program_id,
+            )?;
+            // the approving manager pays the member rent and gets it back on exit
+            let member = MemberAccount {
+                position: group.admit(None),
+                in_promotion: false,
+                in_withdraw: false,
+                council: false,
+                group: group_key,
+                funder: ctx.accounts.authority.key(),
+                owner: user_key,
+                joined_at: now,
+                positioned_at: now,
+                staked: request.staked,
+                stake_mint: request.stake_mint,
+                invite: Pubkey::default(),
+                inviter: Pubkey::default(),
+            };
+            member.try_serialize(&mut &mut ctx.accounts.member.try_borrow_mut_data()?[..])?;
+
+            emit!(JoinGroupEvent {
+                user: user_key,
+                group: group_key,
+                member: ctx.accounts.member.key(),
+                funder: member.funder,
+                time: now,
+                label: "JoinGroup".to_string(),
+            });
+        }
+        if decided == Some(false) && request.staked > 0 {
+            let account_iter = &mut ctx.remaining_accounts.iter();
+            let stake_escrow_token_info = next_account_info(account_iter)?;
+            let user_token_account_info = next_account_info(account_iter)?;
+            let token_program_info = next_account_info(account_iter)?;
+            if token_program_info.key() != token::ID {
+                return Err(ProgramError::IncorrectProgramId.into());
+            }
+            is_stake_escrow(
+                &group_key,
+                &request.stake_mint,
+                stake_escrow_token_info,
+                ctx.program_id,
+            )?;
+            let user_token = Account::<TokenAccount>::try_from(user_token_account_info)?;
+            if user_token.owner != user_key {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::WrongReceiverTokenAccount)
+                } else {
+                    Err(GroupError::WrongReceiverTokenAccount.into())
+                };
+            }
+
+            let seeds = &[
+                group.admin.as_ref(),
+                &group.index.to_le_bytes(),
+                GroupAccount::SEEDS,
+                &[group.seed],
+            ];
+            let signer = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program_info.clone(),
+                    Transfer {
+                        from: stake_escrow_token_info.clone(),
+                        to: user_token_account_info.clone(),
+                        authority: ctx.accounts.group.to_account_info(),
+                    },
+                    signer,
+                ),
+                request.staked,
+            )?;
+            group.stakes -= 1;
+        }
+        request.decision = decided;
+        Ok(())
+    }
+
+    pub fn close_join_request(_ctx: Context<CloseJoinRequest>) -> Result<()> {
         Ok(())
     }
 
-    pub fn exit_group(ctx: Context<ExitGroup>) -> Result<()> {
+    pub fn exit_group<'info>(ctx: Context<'_, '_, '_, 'info, ExitGroup<'info>>) -> Result<()> {
         let group = &mut ctx.accounts.group.load_mut()?;
         if ctx.accounts.member.group != ctx.accounts.group.key() {
             return if cfg!(feature = "dev") {
@@ -190,26 +663,160 @@ pub mod user_group {
             Position::Manager => group.current_manager -= 1,
         }
 
+        let staked = ctx.accounts.member.staked;
+        if staked > 0 {
+            let account_iter = &mut ctx.remaining_accounts.iter();
+            let stake_escrow_token_info = next_account_info(account_iter)?;
+            let owner_token_account_info = next_account_info(account_iter)?;
+            let token_program_info = next_account_info(account_iter)?;
+            if token_program_info.key() != token::ID {
+                return Err(ProgramError::IncorrectProgramId.into());
+            }
+            is_stake_escrow(
+                &ctx.accounts.group.key(),
+                &ctx.accounts.member.stake_mint,
+                stake_escrow_token_info,
+                ctx.program_id,
+            )?;
+            let owner_token = Account::<TokenAccount>::try_from(owner_token_account_info)?;
+            if owner_token.owner != ctx.accounts.member.owner {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::WrongReceiverTokenAccount)
+                } else {
+                    Err(GroupError::WrongReceiverTokenAccount.into())
+                };
+            }
+
+            let seeds = &[
+                group.admin.as_ref(),
+                &group.index.to_le_bytes(),
+                GroupAccount::SEEDS,
+                &[group.seed],
+            ];
+            let signer = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program_info.clone(),
+                    Transfer {
+                        from: stake_escrow_token_info.clone(),
+                        to: owner_token_account_info.clone(),
+                        authority: ctx.accounts.group.to_account_info(),
+                    },
+                    signer,
+                ),
+                staked,
+            )?;
+            group.stakes -= 1;
+        }
+
         emit!(ExitGroupEvent {
             user: ctx.accounts.authority.key().clone(),
             group: ctx.accounts.group.key().clone(),
             member: ctx.accounts.member.key().clone(),
             funder: ctx.accounts.member.funder.clone(),
+            reason: ExitReason::Voluntary,
             time: ctx.accounts.clock.unix_timestamp,
             label: "ExitGroup".to_string(),
         });
         Ok(())
     }
 
+    pub fn migrate_member(ctx: Context<MigrateMember>) -> Result<()> {
+        let member_info = ctx.accounts.member.to_account_info();
+        let member = MemberAccount::try_from_legacy(&member_info.try_borrow_data()?)?;
+        let (address, _) = Pubkey::find_program_address(
+            &[member.group.as_ref(), member.owner.as_ref(), MemberAccount::SEEDS],
+            ctx.program_id,
+        );
+        if member.group != ctx.accounts.group.key() || address != member_info.key() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MismatchedGroup)
+            } else {
+                Err(GroupError::MismatchedGroup.into())
+            };
+        }
+
+        let space = 8 + MemberAccount::LEN;
+        let required = ctx.accounts.rent.minimum_balance(space);
+        if member_info.lamports() < required {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: member_info.clone(),
+                    },
+                ),
+                required - member_info.lamports(),
+            )?;
+        }
+        // fields are inserted before the old ones end, so the account is rewritten whole
+        member_info.realloc(space, true)?;
+        member.try_serialize(&mut &mut member_info.try_borrow_mut_data()?[..])?;
+
+        emit!(MigrateMemberEvent {
+            authority: ctx.accounts.authority.key(),
+            group: ctx.accounts.group.key(),
+            member: member_info.key(),
+            label: "Migrated".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn set_delegation(ctx: Context<SetDelegation>) -> Result<()> {
+        let delegation = &mut ctx.accounts.delegation;
+        delegation.group = ctx.accounts.group.key();
+        delegation.delegator = ctx.accounts.member.key();
+        delegation.delegate = ctx.accounts.delegate.key();
+        delegation.owner = ctx.accounts.authority.key();
+        delegation.created_at = ctx.accounts.clock.unix_timestamp;
+
+        emit!(SetDelegationEvent {
+            owner: delegation.owner,
+            group: delegation.group,
+            delegator: delegation.delegator,
+            delegate: delegation.delegate,
+            delegation: delegation.key(),
+            time: delegation.created_at,
+            label: "SetDelegation".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        let delegation = &ctx.accounts.delegation;
+
+        emit!(RevokeDelegationEvent {
+            owner: delegation.owner,
+            group: delegation.group,
+            delegator: delegation.delegator,
+            delegate: delegation.delegate,
+            delegation: delegation.key(),
+            time: ctx.accounts.clock.unix_timestamp,
+            label: "RevokeDelegation".to_string(),
+        });
+        Ok(())
+    }
+
+    // token account of the next proposal escrowing the submitter bond
+    pub fn create_bond_escrow(_ctx: Context<CreateBondEscrow>) -> Result<()> {
+        Ok(())
+    }
+
     #[access_control(is_valid_deadline(&ctx, deadline))]
     #[access_control(is_valid_limit(limit))]
+    #[access_control(is_valid_metadata(&title, &uri))]
     pub fn submit_proposal<'info>(
         ctx: Context<'_, '_, '_, 'info, SubmitProposal<'info>>,
         prop_type: ProposalType,
         limit: u64,
         deadline: i64,
+        title: String,
+        uri: String,
+        content_hash: [u8; 32],
     ) -> Result<()> {
         let group = &mut ctx.accounts.group.load_mut()?;
+        let index = group.proposals;
         group.proposals += 1;
         let member = &mut ctx.accounts.member;
 
@@ -220,12 +827,35 @@ pub mod user_group {
         proposal.group = ctx.accounts.group.key().clone();
         proposal.positive = 0;
         proposal.negative = 0;
+        proposal.voters = group.eligible_voters(&prop_type) as u32;
         proposal.limit = limit;
         proposal.deadline = deadline;  // 12 hours later than now
         proposal.revoke_timeout = ctx.accounts.clock.unix_timestamp + 7200;  // 2 hours
         proposal.close_timeout = deadline + 259200;  // unused field, retain for future use
+        proposal.snapshot_at = ctx.accounts.clock.unix_timestamp;
+        // ballots are cast openly, commit-reveal only hides a yes/no sign
+        proposal.reveal_period = if prop_type.has_ballot() {
+            0
+        } else {
+            group.config.reveal_period
+        };
+        proposal.escrowed = 0;
+        proposal.index = index;
+        proposal.seed = *ctx.bumps.get("proposal").unwrap();
+        proposal.lock = group.config.lock;
         proposal.proposal_type = prop_type.clone();
-        proposal.status = ProposalStatus::Progressing;
+        proposal.sponsors = 0;
+        proposal.payload_ready = !prop_type.has_payload();
+        proposal.status = if group.config.sponsors > 0 {
+            ProposalStatus::Draft {
+                expire: deadline.min(ctx.accounts.clock.unix_timestamp + group.config.draft_period),
+            }
+        } else {
+            ProposalStatus::Progressing
+        };
+        proposal.title = title;
+        proposal.uri = uri;
+        proposal.content_hash = content_hash;
 
         emit!(SubmitProposalEvent {
             ptype: prop_type.into(),
@@ -236,10 +866,14 @@ pub mod user_group {
             group: proposal.group.clone(),
             proposal: proposal.key().clone(),
             deadline: proposal.deadline,
+            title: proposal.title.clone(),
+            uri: proposal.uri.clone(),
+            content_hash: proposal.content_hash,
             label: "SubmitProposal".to_string(),
         });
 
         let bene_member = &mut ctx.accounts.bene_member;
+        let account_iter = &mut ctx.remaining_accounts.iter();
         // authority check
         match proposal.proposal_type {
             ProposalType::Upgrade => {
@@ -356,8 +990,14 @@ pub mod user_group {
                         Err(GroupError::LastWithdrawNotFinished.into())
                     };
                 }
-                let remaining_accounts = ctx.remaining_accounts;
-                let account_iter = &mut remaining_accounts.iter();
+                // a pending Remove would close the member before the withdraw executes
+                if bene_member.in_promotion {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::AlreadyInProposal)
+                    } else {
+                        Err(GroupError::AlreadyInProposal.into())
+                    };
+                }
                 let group_vault_token_account_info = next_account_info(account_iter)?;
                 if group_vault_token_account_info.lamports() == 0 {
                     return if cfg!(feature = "dev") {
@@ -386,32 +1026,221 @@ pub mod user_group {
 
                 bene_member.in_withdraw = true;
             }
+            ProposalType::Election {
+                candidates,
+                seats,
+                ranked: _,
+            } => {
+                if seats == 0 || seats > candidates || candidates as usize > MAX_CHOICES {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidElection)
+                    } else {
+                        Err(GroupError::InvalidElection.into())
+                    };
+                }
+                if group.current_manager + seats as u32 > group.max_manager {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::UpdateFirst)
+                    } else {
+                        Err(GroupError::UpdateFirst.into())
+                    };
+                }
+                if group.electing {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::LastElectionNotFinished)
+                    } else {
+                        Err(GroupError::LastElectionNotFinished.into())
+                    };
+                }
+                group.electing = true;
+            }
+            ProposalType::Execute { instructions } => {
+                if member.position.is_member() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::NotPermitted)
+                    } else {
+                        Err(GroupError::NotPermitted.into())
+                    };
+                }
+                if instructions == 0 {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidInstructionBuffer)
+                    } else {
+                        Err(GroupError::InvalidInstructionBuffer.into())
+                    };
+                }
+            }
+            ProposalType::Signal { options, .. } => {
+                if member.position.is_member() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::NotPermitted)
+                    } else {
+                        Err(GroupError::NotPermitted.into())
+                    };
+                }
+                if options < 2 || options as usize > MAX_CHOICES {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidSignal)
+                    } else {
+                        Err(GroupError::InvalidSignal.into())
+                    };
+                }
+            }
+            ProposalType::Remove => {
+                if member.position.is_member() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::NotPermitted)
+                    } else {
+                        Err(GroupError::NotPermitted.into())
+                    };
+                }
+
+                if bene_member.in_promotion || bene_member.in_withdraw {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::AlreadyInProposal)
+                    } else {
+                        Err(GroupError::AlreadyInProposal.into())
+                    };
+                }
+
+                bene_member.in_promotion = true;
+            }
+        }
+
+        proposal.bond = group.config.bond;
+        if proposal.bond > 0 {
+            let admin_info = next_account_info(account_iter)?;
+            let submitter_token_account_info = next_account_info(account_iter)?;
+            let escrow_token_account_info = next_account_info(account_iter)?;
+            let token_program_info = next_account_info(account_iter)?;
+            if token_program_info.key() != token::ID {
+                return Err(ProgramError::IncorrectProgramId.into());
+            }
+            let admin = Account::<AdminAccount>::try_from(admin_info)?;
+            is_proposal_escrow(
+                &proposal.key(),
+                ProposalAccount::BOND_ESCROW_SEEDS,
+                escrow_token_account_info,
+                ctx.program_id,
+            )?;
+            let escrow_token = TokenAccount::try_deserialize(
+                &mut &escrow_token_account_info.data.borrow()[..],
+            )?;
+            if admin.key() != group.admin || escrow_token.mint != admin.token_mint {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::WrongVaultTokenAccount)
+                } else {
+                    Err(GroupError::WrongVaultTokenAccount.into())
+                };
+            }
+            token::transfer(
+                CpiContext::new(
+                    token_program_info.clone(),
+                    Transfer {
+                        from: submitter_token_account_info.clone(),
+                        to: escrow_token_account_info.clone(),
+                        authority: ctx.accounts.authority.to_account_info(),
+                    },
+                ),
+                proposal.bond,
+            )?;
         }
         Ok(())
     }
 
-    pub fn update_proposal(ctx: Context<UpdateProposal>, deadline: i64) -> Result<()> {
+    // token account of the proposal escrowing votes under LockMode::Escrow
+    pub fn create_vote_escrow(_ctx: Context<CreateVoteEscrow>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn create_instruction_buffer(
+        ctx: Context<CreateInstructionBuffer>,
+        instructions: Vec<SerializedInstruction>,
+    ) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
-        if proposal.submitter != ctx.accounts.authority.key() {
+        let valid = (proposal.is_progressing() || proposal.is_draft())
+            && matches!(
+                proposal.proposal_type,
+                ProposalType::Execute { instructions: count } if count as usize == instructions.len()
+            );
+        if !valid {
             return if cfg!(feature = "dev") {
-                err!(GroupError::OperationUnauthorized)
+                err!(GroupError::InvalidInstructionBuffer)
             } else {
-                Err(GroupError::OperationUnauthorized.into())
+                Err(GroupError::InvalidInstructionBuffer.into())
             };
         }
-        match proposal.status {
-            ProposalStatus::Progressing => {
-                if deadline <= proposal.deadline {
-                    return if cfg!(feature = "dev") {
-                        err!(GroupError::OnlyAllowedExtend)
-                    } else {
-                        Err(GroupError::OnlyAllowedExtend.into())
-                    };
-                }
 
-                proposal.deadline = deadline;
-                proposal.status = ProposalStatus::Updated {
-                    time: ctx.accounts.clock.unix_timestamp,
+        let buffer = &mut ctx.accounts.buffer;
+        buffer.proposal = proposal.key();
+        buffer.instructions = instructions;
+        proposal.payload_ready = true;
+        Ok(())
+    }
+
+    pub fn create_election<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateElection<'info>>,
+        candidates: Vec<Pubkey>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let valid = (proposal.is_progressing() || proposal.is_draft())
+            && matches!(
+                proposal.proposal_type,
+                ProposalType::Election { candidates: count, .. } if count as usize == candidates.len()
+            )
+            && ctx.remaining_accounts.len() == candidates.len();
+        if !valid {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::InvalidElection)
+            } else {
+                Err(GroupError::InvalidElection.into())
+            };
+        }
+        for (i, candidate_info) in ctx.remaining_accounts.iter().enumerate() {
+            let candidate = Account::<MemberAccount>::try_from(candidate_info)?;
+            if candidate.key() != candidates[i]
+                || candidates[..i].contains(&candidates[i])
+                || candidate.group != ctx.accounts.group.key()
+                || candidate.position.is_manager()
+                || candidate.in_promotion
+            {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::InvalidElection)
+                } else {
+                    Err(GroupError::InvalidElection.into())
+                };
+            }
+        }
+
+        let election = &mut ctx.accounts.election;
+        election.proposal = proposal.key();
+        election.candidates = candidates;
+        proposal.payload_ready = true;
+        Ok(())
+    }
+
+    pub fn update_proposal(ctx: Context<UpdateProposal>, deadline: i64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        if proposal.submitter != ctx.accounts.authority.key() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::OperationUnauthorized)
+            } else {
+                Err(GroupError::OperationUnauthorized.into())
+            };
+        }
+        match proposal.status {
+            ProposalStatus::Progressing => {
+                if deadline <= proposal.deadline {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::OnlyAllowedExtend)
+                    } else {
+                        Err(GroupError::OnlyAllowedExtend.into())
+                    };
+                }
+
+                proposal.deadline = deadline;
+                proposal.status = ProposalStatus::Updated {
+                    time: ctx.accounts.clock.unix_timestamp,
                 };
 
                 emit!(UpdateProposalEvent {
@@ -441,11 +1270,37 @@ pub mod user_group {
         }
     }
 
+    #[access_control(is_valid_metadata(&title, &uri))]
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        title: String,
+        uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.title = title;
+        proposal.uri = uri;
+        proposal.content_hash = content_hash;
+
+        emit!(UpdateMetadataEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            submitter: proposal.submitter,
+            title: proposal.title.clone(),
+            uri: proposal.uri.clone(),
+            content_hash: proposal.content_hash,
+            label: "UpdateMetadata".to_string(),
+        });
+        Ok(())
+    }
+
     #[access_control(is_not_outdated(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
-    pub fn revoke_proposal(ctx: Context<RevokeProposal>) -> Result<()> {
+    pub fn revoke_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeProposal<'info>>,
+    ) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
         match proposal.proposal_type {
-            ProposalType::Upgrade | ProposalType::Downgrade => {
+            ProposalType::Upgrade | ProposalType::Downgrade | ProposalType::Remove => {
                 let bene_member = &mut ctx.accounts.bene_member;
                 bene_member.in_promotion = false;
             }
@@ -461,9 +1316,129 @@ pub mod user_group {
                 let group = &mut ctx.accounts.group.load_mut()?;
                 group.update = false;
             }
-            #[allow(unreachable_patterns)]
-            _ => unreachable!(),
+            ProposalType::Execute { instructions: _ } => {}
+            ProposalType::Election { .. } => {
+                let group = &mut ctx.accounts.group.load_mut()?;
+                group.electing = false;
+            }
+            ProposalType::Signal { .. } => {}
+        }
+
+        let bond = proposal.bond;
+        if bond > 0 {
+            let account_iter = &mut ctx.remaining_accounts.iter();
+            let submitter_token_account_info = next_account_info(account_iter)?;
+            let escrow_token_account_info = next_account_info(account_iter)?;
+            let token_program_info = next_account_info(account_iter)?;
+            if token_program_info.key() != token::ID {
+                return Err(ProgramError::IncorrectProgramId.into());
+            }
+            is_proposal_escrow(
+                &proposal.key(),
+                ProposalAccount::BOND_ESCROW_SEEDS,
+                escrow_token_account_info,
+                ctx.program_id,
+            )?;
+
+            let seeds = &[
+                proposal.group.as_ref(),
+                &proposal.index.to_le_bytes(),
+                ProposalAccount::SEEDS,
+                &[proposal.seed],
+            ];
+            let signer = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program_info.clone(),
+                    Transfer {
+                        from: escrow_token_account_info.clone(),
+                        to: submitter_token_account_info.clone(),
+                        authority: proposal.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond,
+            )?;
+            proposal.bond = 0;
+
+            emit!(SettleBondEvent {
+                group: proposal.group,
+                proposal: proposal.key(),
+                submitter: proposal.submitter,
+                amount: bond,
+                refunded: true,
+                label: "SettleBond".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn settle_bond(ctx: Context<SettleBond>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let refunded = match proposal.is_bond_refundable() {
+            Some(refunded) => refunded,
+            None => {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::ProposalNotFinished)
+                } else {
+                    Err(GroupError::ProposalNotFinished.into())
+                };
+            }
+        };
+
+        let bond = proposal.bond;
+        let seeds = &[
+            proposal.group.as_ref(),
+            &proposal.index.to_le_bytes(),
+            ProposalAccount::SEEDS,
+            &[proposal.seed],
+        ];
+        let signer = &[&seeds[..]];
+        let token_program = ctx.accounts.token_program.to_account_info();
+        if refunded || ctx.accounts.group.load()?.config.slash == SlashMode::Treasury {
+            let to = if refunded {
+                ctx.accounts.submitter_token.to_account_info()
+            } else {
+                ctx.accounts.vault.to_account_info()
+            };
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program,
+                    Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to,
+                        authority: proposal.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond,
+            )?;
+        } else {
+            token::burn(
+                CpiContext::new_with_signer(
+                    token_program,
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.escrow.to_account_info(),
+                        authority: proposal.to_account_info(),
+                    },
+                    signer,
+                ),
+                bond,
+            )?;
         }
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.bond = 0;
+
+        emit!(SettleBondEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            submitter: proposal.submitter,
+            amount: bond,
+            refunded,
+            label: "SettleBond".to_string(),
+        });
         Ok(())
     }
 
@@ -471,281 +1446,892 @@ pub mod user_group {
     pub fn sign_proposal<'info>(
         ctx: Context<'_, '_, '_, 'info, SignProposal<'info>>,
         sign: SignType,
+        amount: u64,
+        choices: Vec<u8>,
     ) -> Result<()> {
-        token::burn(
-            ctx.accounts.as_token_burn_ctx(),
-            ctx.accounts.proposal.limit,
-        )?;
+        // Delegated votes
+        // delegator_member_info, delegation_info, signature_info isWritable: true
+        let delegated_accounts = ctx.remaining_accounts.chunks_exact(3);
+        if !delegated_accounts.remainder().is_empty() {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let votes = delegated_accounts.len() as u64 + 1;
+        let total = match amount.checked_mul(votes) {
+            Some(total) if total <= ctx.accounts.token.amount => total,
+            _ => {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::InsufficientTokenBalance)
+                } else {
+                    Err(GroupError::InsufficientTokenBalance.into())
+                };
+            }
+        };
+
+        match ctx.accounts.proposal.lock {
+            LockMode::Burn => token::burn(ctx.accounts.as_token_burn_ctx(), total)?,
+            LockMode::Escrow => {
+                is_proposal_escrow(
+                    &ctx.accounts.proposal.key(),
+                    ProposalAccount::VOTE_ESCROW_SEEDS,
+                    &ctx.accounts.vault.to_account_info(),
+                    ctx.program_id,
+                )?;
+                token::transfer(ctx.accounts.as_token_lock_ctx(), total)?;
+                ctx.accounts.proposal.escrowed += total;
+            }
+            LockMode::Treasury => {
+                if ctx.accounts.vault.owner != ctx.accounts.group.key() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::WrongVaultTokenAccount)
+                    } else {
+                        Err(GroupError::WrongVaultTokenAccount.into())
+                    };
+                }
+                token::transfer(ctx.accounts.as_token_lock_ctx(), total)?;
+            }
+        }
+        let group_key = ctx.accounts.group.key();
+        let member_key = ctx.accounts.member.key();
+        let vote_weight = ctx.accounts.group.load()?.config.weight;
+        let weight = vote_weight.of(amount);
+        let choice_weight = vote_weight.of_choice(weight);
         let proposal = &mut ctx.accounts.proposal;
+        let proposal_key = proposal.key();
         let signature = &mut ctx.accounts.signature;
         signature.signer = ctx.accounts.authority.key();
         signature.created_at = ctx.accounts.clock.unix_timestamp;
-        signature.amount = proposal.limit;
-        signature.proposal = proposal.key().clone();
+        signature.amount = amount;
+        signature.weight = weight;
+        signature.proposal = proposal_key;
+        signature.member = member_key;
         signature.sign_type = sign.clone();
+        signature.commitment = None;
+        signature.choices = choices.clone();
+        proposal.add_vote(&sign, weight);
+        proposal.add_choices(&choices, choice_weight);
+
+        emit!(SignProposalEvent {
+            ptype: proposal.proposal_type.clone().into(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal_key,
+            stype: sign.clone(),
+            amount,
+            weight,
+            signer: signature.signer,
+            member: member_key,
+            signature: signature.key(),
+            label: "SignProposal".to_string(),
+        });
+
+        for accounts in delegated_accounts {
+            let delegator = Account::<MemberAccount>::try_from(&accounts[0])?;
+            let delegation = Account::<DelegationAccount>::try_from(&accounts[1])?;
+            let signature_info = &accounts[2];
+            if delegator.group != group_key {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::VoterNotInGroup)
+                } else {
+                    Err(GroupError::VoterNotInGroup.into())
+                };
+            }
+            if !proposal.proposal_type.is_voter(&delegator.position) {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::PositionNotEligible)
+                } else {
+                    Err(GroupError::PositionNotEligible.into())
+                };
+            }
+            if delegator.joined_at > proposal.snapshot_at {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::JoinedAfterSnapshot)
+                } else {
+                    Err(GroupError::JoinedAfterSnapshot.into())
+                };
+            }
+            if !proposal.counts_position_of(&delegator) {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::PositionChangedAfterSnapshot)
+                } else {
+                    Err(GroupError::PositionChangedAfterSnapshot.into())
+                };
+            }
+            if delegation.group != group_key
+                || delegation.delegator != delegator.key()
+                || delegation.delegate != member_key
+            {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::MismatchedDelegation)
+                } else {
+                    Err(GroupError::MismatchedDelegation.into())
+                };
+            }
+
+            // same seeds as the signature of a direct vote, so a member votes only once
+            let delegator_key = delegator.key();
+            let (address, bump) = Pubkey::find_program_address(
+                &[group_key.as_ref(), delegator_key.as_ref(), proposal_key.as_ref()],
+                ctx.program_id,
+            );
+            if address != signature_info.key() {
+                return Err(ErrorCode::ConstraintSeeds.into());
+            }
+            let seeds = &[
+                group_key.as_ref(),
+                delegator_key.as_ref(),
+                proposal_key.as_ref(),
+                &[bump],
+            ];
+            create_pda_account(
+                &ctx.accounts.authority.to_account_info(),
+                signature_info,
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.rent,
+                8 + SignatureAccount::LEN,
+                seeds,
+                ctx.program_id,
+            )?;
+
+            let delegated = SignatureAccount {
+                signer: ctx.accounts.authority.key(),
+                created_at: ctx.accounts.clock.unix_timestamp,
+                amount,
+                weight,
+                proposal: proposal_key,
+                member: delegator_key,
+                sign_type: sign.clone(),
+                commitment: None,
+                choices: choices.clone(),
+            };
+            delegated.try_serialize(&mut &mut signature_info.try_borrow_mut_data()?[..])?;
+            proposal.add_vote(&sign, weight);
+            proposal.add_choices(&choices, choice_weight);
+
+            emit!(SignProposalEvent {
+                ptype: proposal.proposal_type.clone().into(),
+                submitter: proposal.submitter,
+                beneficiary: proposal.beneficiary,
+                group: proposal.group,
+                proposal: proposal_key,
+                stype: sign.clone(),
+                amount,
+                weight,
+                signer: delegated.signer,
+                member: delegator_key,
+                signature: signature_info.key(),
+                label: "SignProposal".to_string(),
+            });
+        }
+
+        let group = &mut ctx.accounts.group.load_mut()?;
+        if let Some(approved) = proposal.tally(group, false) {
+            let result = proposal.resolve(
+                approved,
+                ctx.accounts.clock.unix_timestamp,
+                group,
+                &mut ctx.accounts.bene_member,
+            );
+
+            emit!(ExecuteProposalEvent {
+                ptype: proposal.proposal_type.clone().into(),
+                submitter: proposal.submitter,
+                beneficiary: proposal.beneficiary,
+                group: proposal.group,
+                proposal: proposal.key(),
+                result,
+                label: "ExecuteProposal".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    #[access_control(is_valid_elapse(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32], amount: u64) -> Result<()> {
+        match ctx.accounts.proposal.lock {
+            LockMode::Burn => token::burn(ctx.accounts.as_token_burn_ctx(), amount)?,
+            LockMode::Escrow => {
+                is_proposal_escrow(
+                    &ctx.accounts.proposal.key(),
+                    ProposalAccount::VOTE_ESCROW_SEEDS,
+                    &ctx.accounts.vault.to_account_info(),
+                    ctx.program_id,
+                )?;
+                token::transfer(ctx.accounts.as_token_lock_ctx(), amount)?;
+                ctx.accounts.proposal.escrowed += amount;
+            }
+            LockMode::Treasury => {
+                if ctx.accounts.vault.owner != ctx.accounts.group.key() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::WrongVaultTokenAccount)
+                    } else {
+                        Err(GroupError::WrongVaultTokenAccount.into())
+                    };
+                }
+                token::transfer(ctx.accounts.as_token_lock_ctx(), amount)?;
+            }
+        }
+        let weight = ctx.accounts.group.load()?.config.weight.of(amount);
+        let proposal = &ctx.accounts.proposal;
+        let signature = &mut ctx.accounts.signature;
+        signature.signer = ctx.accounts.authority.key();
+        signature.created_at = ctx.accounts.clock.unix_timestamp;
+        signature.amount = amount;
+        signature.weight = weight;
+        signature.proposal = proposal.key();
+        signature.member = ctx.accounts.member.key();
+        signature.sign_type = SignType::Abstain;
+        signature.commitment = Some(commitment);
+        signature.choices = vec![];
+
+        emit!(CommitVoteEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            commitment,
+            amount,
+            signer: signature.signer,
+            member: signature.member,
+            signature: signature.key(),
+            label: "CommitVote".to_string(),
+        });
+        Ok(())
+    }
 
-        match signature.sign_type {
-            SignType::Agreed => proposal.positive += 1,
-            SignType::Denied => proposal.negative += 1,
+    pub fn reveal_vote(ctx: Context<RevealVote>, sign: SignType, salt: [u8; 32]) -> Result<()> {
+        let now = ctx.accounts.clock.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        if now <= proposal.deadline {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalNotOutdated)
+            } else {
+                Err(GroupError::ProposalNotOutdated.into())
+            };
+        }
+        if now > proposal.voting_end() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalOutdated)
+            } else {
+                Err(GroupError::ProposalOutdated.into())
+            };
+        }
+        let signature = &mut ctx.accounts.signature;
+        let commitment = SignatureAccount::commit(&proposal.key(), &signature.member, &sign, &salt);
+        if signature.commitment != Some(commitment) {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::InvalidCommitment)
+            } else {
+                Err(GroupError::InvalidCommitment.into())
+            };
         }
+        signature.sign_type = sign.clone();
+        signature.commitment = None;
+        proposal.add_vote(&sign, signature.weight);
 
         emit!(SignProposalEvent {
             ptype: proposal.proposal_type.clone().into(),
-            submitter: proposal.submitter.clone(),
-            beneficiary: proposal.beneficiary.clone(),
-            group: proposal.group.clone(),
-            proposal: proposal.key().clone(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal.key(),
             stype: sign,
-            signer: signature.signer.clone(),
-            signature: signature.key().clone(),
+            amount: signature.amount,
+            weight: signature.weight,
+            signer: signature.signer,
+            member: signature.member,
+            signature: signature.key(),
             label: "SignProposal".to_string(),
         });
+        Ok(())
+    }
+
+    #[access_control(is_valid_elapse(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
+    pub fn change_vote(ctx: Context<ChangeVote>, sign: SignType) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let signature = &mut ctx.accounts.signature;
+        let from = signature.sign_type.clone();
+        proposal.remove_vote(&from, signature.weight);
+        proposal.add_vote(&sign, signature.weight);
+        signature.sign_type = sign.clone();
 
-        let hence = ctx.accounts.group.load()?.hence();
+        emit!(ChangeVoteEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            from,
+            to: sign,
+            amount: signature.amount,
+            signer: signature.signer,
+            member: signature.member,
+            signature: signature.key(),
+            label: "ChangeVote".to_string(),
+        });
+        Ok(())
+    }
 
-        if proposal.participated() >= hence {
-            if proposal.positive > proposal.negative {
-                proposal.status = ProposalStatus::Passed {
-                    time: ctx.accounts.clock.unix_timestamp,
+    #[access_control(is_valid_elapse(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
+    pub fn retract_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, RetractVote<'info>>,
+    ) -> Result<()> {
+        let amount = ctx.accounts.signature.amount;
+        if ctx.accounts.proposal.lock == LockMode::Escrow && amount > 0 {
+            let remaining_accounts = ctx.remaining_accounts;
+            let account_iter = &mut remaining_accounts.iter();
+            let escrow_token_account_info = next_account_info(account_iter)?;
+            let user_token_account_info = next_account_info(account_iter)?;
+            is_proposal_escrow(
+                &ctx.accounts.proposal.key(),
+                ProposalAccount::VOTE_ESCROW_SEEDS,
+                escrow_token_account_info,
+                ctx.program_id,
+            )?;
+
+            let proposal = &ctx.accounts.proposal;
+            let seeds = &[
+                proposal.group.as_ref(),
+                &proposal.index.to_le_bytes(),
+                ProposalAccount::SEEDS,
+                &[proposal.seed],
+            ];
+            let signer = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: escrow_token_account_info.clone(),
+                        to: user_token_account_info.clone(),
+                        authority: proposal.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+            )?;
+            ctx.accounts.proposal.escrowed -= amount;
+        }
+
+        let signature = &ctx.accounts.signature;
+        let choice_weight = ctx.accounts.group.load()?.config.weight.of_choice(signature.weight);
+        let proposal = &mut ctx.accounts.proposal;
+        if signature.is_revealed() {
+            proposal.remove_vote(&signature.sign_type, signature.weight);
+            proposal.remove_choices(&signature.choices, choice_weight);
+        }
+
+        emit!(RetractVoteEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            stype: signature.sign_type.clone(),
+            amount,
+            signer: signature.signer,
+            member: signature.member,
+            signature: signature.key(),
+            label: "RetractVote".to_string(),
+        });
+        Ok(())
+    }
+
+    #[access_control(is_executable(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        let mut bene_member = Account::<MemberAccount>::try_from(&bene_member_info)?;
+        if bene_member.group != ctx.accounts.group.key() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MismatchedGroup)
+            } else {
+                Err(GroupError::MismatchedGroup.into())
+            };
+        }
+        if bene_member.owner != proposal.beneficiary {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MismatchedBeneMember)
+            } else {
+                Err(GroupError::MismatchedBeneMember.into())
+            };
+        }
+        let group = &mut ctx.accounts.group.load_mut()?;
+        let remaining_accounts = ctx.remaining_accounts;
+
+        match proposal.proposal_type {
+            ProposalType::Upgrade => {
+                bene_member.position = Position::Manager;
+                bene_member.positioned_at = ctx.accounts.clock.unix_timestamp;
+                bene_member.in_promotion = false;
+
+                group.current_member -= 1;
+                group.current_manager += 1;
+            }
+            ProposalType::Downgrade => {
+                bene_member.position = Position::Member;
+                bene_member.positioned_at = ctx.accounts.clock.unix_timestamp;
+                bene_member.in_promotion = false;
+                bene_member.council = false;
+
+                group.current_manager -= 1;
+                group.current_member += 1;
+            }
+            ProposalType::UpdateGroup { max_manager } => {
+                group.max_manager = max_manager;
+                group.update = false;
+            }
+            ProposalType::ReElection => {
+                bene_member.position = Position::Manager;
+                bene_member.positioned_at = ctx.accounts.clock.unix_timestamp;
+                bene_member.in_promotion = false;
+
+                group.current_member -= 1;
+                group.current_manager += 1;
+                group.electing = false;
+            }
+            ProposalType::Withdraw {
+                mint,
+                receiver,
+                amount,
+            } => {
+                let account_iter = &mut remaining_accounts.iter();
+                let group_token_account_info = next_account_info(account_iter)?;
+                let user_token_account_info = next_account_info(account_iter)?;
+                let group_token = TokenAccount::try_deserialize(
+                    &mut &group_token_account_info.data.borrow()[..],
+                )?;
+                if group_token.mint != mint {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::MismatchedToken)
+                    } else {
+                        Err(GroupError::MismatchedToken.into())
+                    };
+                }
+                if group_token.owner != ctx.accounts.group.key() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::WrongVaultTokenAccount)
+                    } else {
+                        Err(GroupError::WrongVaultTokenAccount.into())
+                    };
+                }
+                if group_token.amount < amount {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InsufficientTokenBalance)
+                    } else {
+                        Err(GroupError::InsufficientTokenBalance.into())
+                    };
+                }
+                if user_token_account_info.key() != receiver {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::WrongReceiverTokenAccount)
+                    } else {
+                        Err(GroupError::WrongReceiverTokenAccount.into())
+                    };
+                }
+                bene_member.in_withdraw = false;
+
+                let seeds = &[
+                    group.admin.as_ref(),
+                    &group.index.to_le_bytes(),
+                    GroupAccount::SEEDS,
+                    &[group.seed],
+                ];
+                let signer = &[&seeds[..]];
+                let ix = spl_token::instruction::transfer(
+                    &spl_token::ID,
+                    group_token_account_info.key,
+                    user_token_account_info.key,
+                    &ctx.accounts.group.key(),
+                    &[],
+                    amount,
+                )?;
+                invoke_signed_unchecked(
+                    &ix,
+                    &[
+                        group_token_account_info.clone(),
+                        user_token_account_info.clone(),
+                        ctx.accounts.group.to_account_info().clone(),
+                    ],
+                    signer,
+                )?;
+            }
+            ProposalType::Execute { instructions } => {
+                let (buffer_info, account_infos) = match remaining_accounts.split_first() {
+                    Some(split) => split,
+                    None => return Err(ErrorCode::AccountNotEnoughKeys.into()),
                 };
-                let group = &mut ctx.accounts.group.load_mut()?;
-                let remaining_accounts = ctx.remaining_accounts;
+                if buffer_info.owner != &crate::ID {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidInstructionBuffer)
+                    } else {
+                        Err(GroupError::InvalidInstructionBuffer.into())
+                    };
+                }
+                let buffer = InstructionBufferAccount::try_deserialize(
+                    &mut &buffer_info.data.borrow()[..],
+                )?;
+                if buffer.proposal != proposal.key()
+                    || buffer.instructions.len() != instructions as usize
+                {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidInstructionBuffer)
+                    } else {
+                        Err(GroupError::InvalidInstructionBuffer.into())
+                    };
+                }
 
-                emit!(ExecuteProposalEvent {
-                    ptype: proposal.proposal_type.clone().into(),
-                    submitter: proposal.submitter.clone(),
-                    beneficiary: proposal.beneficiary.clone(),
-                    group: proposal.group.clone(),
-                    proposal: proposal.key().clone(),
-                    result: ProposalResult::Passed,
-                    label: "ExecuteProposal".to_string(),
-                });
+                let seeds = &[
+                    group.admin.as_ref(),
+                    &group.index.to_le_bytes(),
+                    GroupAccount::SEEDS,
+                    &[group.seed],
+                ];
+                let signer = &[&seeds[..]];
+                let mut infos = account_infos.to_vec();
+                infos.push(ctx.accounts.group.to_account_info());
+                for ix in buffer.instructions.iter() {
+                    invoke_signed_unchecked(&ix.into(), &infos, signer)?;
+                }
+            }
+            ProposalType::Election { candidates, .. } => {
+                // election_info, candidate_member_info isWritable: true in election order
+                let (election_info, candidate_infos) = match remaining_accounts.split_first() {
+                    Some(split) => split,
+                    None => return Err(ErrorCode::AccountNotEnoughKeys.into()),
+                };
+                let election = Account::<ElectionAccount>::try_from(election_info)?;
+                if election.proposal != proposal.key()
+                    || election.candidates.len() != candidates as usize
+                    || candidate_infos.len() != election.candidates.len()
+                {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::InvalidElection)
+                    } else {
+                        Err(GroupError::InvalidElection.into())
+                    };
+                }
 
-                match proposal.proposal_type {
-                    ProposalType::Upgrade => {
-                        {
-                            let account_iter = &mut remaining_accounts.iter();
-                            let bene_member_info = next_account_info(account_iter)?;
-                            let mut bene_member: MemberAccount =
-                                AccountDeserialize::try_deserialize(
-                                    &mut &bene_member_info.data.borrow()[..],
-                                )?;
-                            if bene_member.owner != proposal.beneficiary {
-                                return if cfg!(feature = "dev") {
-                                    err!(GroupError::MismatchedBeneMember)
-                                } else {
-                                    Err(GroupError::MismatchedBeneMember.into())
-                                };
-                            }
-                            bene_member.position = Position::Manager;
-                            bene_member.in_promotion = false;
-                            bene_member
-                                .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-                        }
-
-                        group.current_member -= 1;
-                        group.current_manager += 1;
+                for index in proposal.elected() {
+                    let candidate_info = &candidate_infos[index];
+                    if candidate_info.key() != election.candidates[index] {
+                        return if cfg!(feature = "dev") {
+                            err!(GroupError::InvalidElection)
+                        } else {
+                            Err(GroupError::InvalidElection.into())
+                        };
+                    }
+                    // skip candidates gone since nomination
+                    if candidate_info.owner != ctx.program_id || candidate_info.data_is_empty() {
+                        continue;
                     }
-                    ProposalType::Downgrade => {
-                        {
-                            let account_iter = &mut remaining_accounts.iter();
-                            let bene_member_info = next_account_info(account_iter)?;
-                            let mut bene_member: MemberAccount =
-                                AccountDeserialize::try_deserialize(
-                                    &mut &bene_member_info.data.borrow()[..],
-                                )?;
-                            if bene_member.owner != proposal.beneficiary {
-                                return if cfg!(feature = "dev") {
-                                    err!(GroupError::MismatchedBeneMember)
-                                } else {
-                                    Err(GroupError::MismatchedBeneMember.into())
-                                };
-                            }
-                            bene_member.position = Position::Member;
-                            bene_member.in_promotion = false;
-                            bene_member
-                                .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-                        }
-
-                        group.current_manager -= 1;
-                        group.current_member += 1;
+                    let mut candidate = Account::<MemberAccount>::try_from(candidate_info)?;
+                    // skip candidates promoted or locked by another proposal since nomination
+                    if candidate.group != ctx.accounts.group.key()
+                        || candidate.position.is_manager()
+                        || candidate.in_promotion
+                        || group.current_manager >= group.max_manager
+                    {
+                        continue;
                     }
-                    ProposalType::UpdateGroup { max_manager } => {
-                        group.max_manager = max_manager;
-                        group.update = false;
+                    candidate.position = Position::Manager;
+                    candidate.positioned_at = ctx.accounts.clock.unix_timestamp;
+                    group.current_member -= 1;
+                    group.current_manager += 1;
+                    candidate.exit(ctx.program_id)?;
+                }
+                group.electing = false;
+            }
+            // never queued
+            ProposalType::Signal { .. } => {}
+            ProposalType::Remove => {
+                let account_iter = &mut remaining_accounts.iter();
+                let funder_info = next_account_info(account_iter)?;
+                if funder_info.key() != bene_member.funder {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::MismatchedFunder)
+                    } else {
+                        Err(GroupError::MismatchedFunder.into())
+                    };
+                }
+
+                if bene_member.staked > 0 {
+                    let stake_escrow_token_info = next_account_info(account_iter)?;
+                    let owner_token_account_info = next_account_info(account_iter)?;
+                    let token_program_info = next_account_info(account_iter)?;
+                    if token_program_info.key() != token::ID {
+                        return Err(ProgramError::IncorrectProgramId.into());
                     }
-                    ProposalType::ReElection => {
-                        {
-                            let account_iter = &mut remaining_accounts.iter();
-                            let bene_member_info = next_account_info(account_iter)?;
-                            let mut bene_member: MemberAccount =
-                                AccountDeserialize::try_deserialize(
-                                    &mut &bene_member_info.data.borrow()[..],
-                                )?;
-                            if bene_member.owner != proposal.beneficiary {
-                                return if cfg!(feature = "dev") {
-                                    err!(GroupError::MismatchedBeneMember)
-                                } else {
-                                    Err(GroupError::MismatchedBeneMember.into())
-                                };
-                            }
-                            bene_member.position = Position::Manager;
-                            bene_member.in_promotion = false;
-                            bene_member
-                                .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-                        }
-
-                        group.current_member -= 1;
-                        group.current_manager += 1;
-                        group.electing = false;
+                    is_stake_escrow(
+                        &ctx.accounts.group.key(),
+                        &bene_member.stake_mint,
+                        stake_escrow_token_info,
+                        ctx.program_id,
+                    )?;
+                    let owner_token = Account::<TokenAccount>::try_from(owner_token_account_info)?;
+                    if owner_token.owner != bene_member.owner {
+                        return if cfg!(feature = "dev") {
+                            err!(GroupError::WrongReceiverTokenAccount)
+                        } else {
+                            Err(GroupError::WrongReceiverTokenAccount.into())
+                        };
                     }
-                    ProposalType::Withdraw {
-                        mint,
-                        receiver,
-                        amount,
-                    } => {
-                        let account_iter = &mut remaining_accounts.iter();
-                        let bene_member_info = next_account_info(account_iter)?;
-                        let group_token_account_info = next_account_info(account_iter)?;
-                        let user_token_account_info = next_account_info(account_iter)?;
-                        let mut bene_member: MemberAccount = AccountDeserialize::try_deserialize(
-                            &mut &bene_member_info.data.borrow()[..],
-                        )?;
-                        if bene_member.owner != proposal.beneficiary {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::MismatchedBeneMember)
-                            } else {
-                                Err(GroupError::MismatchedBeneMember.into())
-                            };
-                        }
-                        let group_token = TokenAccount::try_deserialize(
-                            &mut &group_token_account_info.data.borrow()[..],
-                        )?;
-                        if group_token.mint != mint {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::MismatchedToken)
-                            } else {
-                                Err(GroupError::MismatchedToken.into())
-                            };
-                        }
-                        if group_token.owner != ctx.accounts.group.key() {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::WrongVaultTokenAccount)
-                            } else {
-                                Err(GroupError::WrongVaultTokenAccount.into())
-                            };
-                        }
-                        if group_token.amount < amount {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::InsufficientTokenBalance)
-                            } else {
-                                Err(GroupError::InsufficientTokenBalance.into())
-                            };
-                        }
-                        if user_token_account_info.key() != receiver {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::WrongReceiverTokenAccount)
-                            } else {
-                                Err(GroupError::WrongReceiverTokenAccount.into())
-                            };
-                        }
-                        bene_member.in_withdraw = false;
-                        bene_member
-                            .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-
-                        let seeds = &[
-                            group.admin.as_ref(),
-                            &group.index.to_le_bytes(),
-                            GroupAccount::SEEDS,
-                            &[group.seed],
-                        ];
-                        let signer = &[&seeds[..]];
-                        let ix = spl_token::instruction::transfer(
-                            &spl_token::ID,
-                            group_token_account_info.key,
-                            user_token_account_info.key,
-                            &ctx.accounts.group.key(),
-                            &[],
-                            amount,
-                        )?;
-                        invoke_signed_unchecked(
-                            &ix,
-                            &[
-                                group_token_account_info.clone(),
-                                user_token_account_info.clone(),
-                                ctx.accounts.group.to_account_info().clone(),
-                            ],
+
+                    let seeds = &[
+                        group.admin.as_ref(),
+                        &group.index.to_le_bytes(),
+                        GroupAccount::SEEDS,
+                        &[group.seed],
+                    ];
+                    let signer = &[&seeds[..]];
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program_info.clone(),
+                            Transfer {
+                                from: stake_escrow_token_info.clone(),
+                                to: owner_token_account_info.clone(),
+                                authority: ctx.accounts.group.to_account_info(),
+                            },
                             signer,
-                        )?;
-                    }
+                        ),
+                        bene_member.staked,
+                    )?;
+                    group.stakes -= 1;
                 }
-            } else {
-                emit!(ExecuteProposalEvent {
-                    ptype: proposal.proposal_type.clone().into(),
-                    submitter: proposal.submitter.clone(),
-                    beneficiary: proposal.beneficiary.clone(),
-                    group: proposal.group.clone(),
-                    proposal: proposal.key().clone(),
-                    result: ProposalResult::Rejected,
-                    label: "ExecuteProposal".to_string(),
-                });
 
-                proposal.status = ProposalStatus::Rejected {
+                match bene_member.position {
+                    Position::Member => group.current_member -= 1,
+                    Position::Manager => group.current_manager -= 1,
+                }
+                close_pda_account(&bene_member_info, funder_info)?;
+
+                emit!(ExitGroupEvent {
+                    user: bene_member.owner,
+                    group: ctx.accounts.group.key(),
+                    member: bene_member.key(),
+                    funder: funder_info.key(),
+                    reason: ExitReason::Removed,
                     time: ctx.accounts.clock.unix_timestamp,
+                    label: "ExitGroup".to_string(),
+                });
+            }
+        }
+        // a removed member is closed, an election leaves the beneficiary untouched and may
+        // have promoted it as a candidate, every other beneficiary is written back
+        if !matches!(
+            proposal.proposal_type,
+            ProposalType::Remove | ProposalType::Election { .. }
+        ) {
+            bene_member.exit(ctx.program_id)?;
+        }
+        proposal.status = ProposalStatus::Passed {
+            time: ctx.accounts.clock.unix_timestamp,
+        };
+
+        emit!(ExecuteProposalEvent {
+            ptype: proposal.proposal_type.clone().into(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal.key(),
+            result: ProposalResult::Passed,
+            label: "ExecuteProposal".to_string(),
+        });
+        Ok(())
+    }
+
+    #[access_control(is_admin(&ctx.accounts.admin, ctx.accounts.authority.key))]
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        if !matches!(proposal.status, ProposalStatus::Queued { .. }) {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalNotQueued)
+            } else {
+                Err(GroupError::ProposalNotQueued.into())
+            };
+        }
+
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        let mut bene_member = load_bene_member(proposal, &bene_member_info, ctx.program_id)?;
+        // a closed member holds no locks, only those on the group are left
+        let mut closed = MemberAccount::default();
+        let group = &mut ctx.accounts.group.load_mut()?;
+        proposal.release_locks(group, bene_member.as_deref_mut().unwrap_or(&mut closed));
+        if let Some(bene_member) = bene_member {
+            bene_member.exit(ctx.program_id)?;
+        }
+        proposal.status = ProposalStatus::Canceled {
+            time: ctx.accounts.clock.unix_timestamp,
+        };
+
+        emit!(ExecuteProposalEvent {
+            ptype: proposal.proposal_type.clone().into(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal.key(),
+            result: ProposalResult::Canceled,
+            label: "ExecuteProposal".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn sponsor_proposal(ctx: Context<SponsorProposal>) -> Result<()> {
+        let now = ctx.accounts.clock.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        if proposal.is_draft_expired(now) {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalOutdated)
+            } else {
+                Err(GroupError::ProposalOutdated.into())
+            };
+        }
+
+        let sponsorship = &mut ctx.accounts.sponsorship;
+        sponsorship.proposal = proposal.key();
+        sponsorship.member = ctx.accounts.member.key();
+        sponsorship.sponsor = ctx.accounts.authority.key();
+        sponsorship.created_at = now;
+
+        let required = ctx.accounts.group.load()?.config.sponsors;
+        let opened = proposal.add_sponsor(required);
+
+        emit!(SponsorProposalEvent {
+            group: proposal.group,
+            proposal: proposal.key(),
+            sponsor: sponsorship.sponsor,
+            member: sponsorship.member,
+            sponsors: proposal.sponsors,
+            opened,
+            label: "SponsorProposal".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        if !ctx.accounts.admin.is_admin(&authority) {
+            let account_iter = &mut ctx.remaining_accounts.iter();
+            let council_member_info = next_account_info(account_iter)?;
+            let council_member = Account::<MemberAccount>::try_from(council_member_info)?;
+            if council_member.owner != authority
+                || council_member.group != ctx.accounts.group.key()
+                || !council_member.council
+                || !council_member.position.is_manager()
+            {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::OperationUnauthorized)
+                } else {
+                    Err(GroupError::OperationUnauthorized.into())
                 };
-                if let ProposalType::ReElection = proposal.proposal_type {
-                    let group = &mut ctx.accounts.group.load_mut()?;
-                    group.electing = false;
-                }
-                let remaining_accounts = ctx.remaining_accounts;
-                match proposal.proposal_type {
-                    ProposalType::Upgrade | ProposalType::Downgrade | ProposalType::ReElection => {
-                        let account_iter = &mut remaining_accounts.iter();
-                        let bene_member_info = next_account_info(account_iter)?;
-                        let mut bene_member: MemberAccount = AccountDeserialize::try_deserialize(
-                            &mut &bene_member_info.data.borrow()[..],
-                        )?;
-                        if bene_member.owner != proposal.beneficiary {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::MismatchedBeneMember)
-                            } else {
-                                Err(GroupError::MismatchedBeneMember.into())
-                            };
-                        }
-                        bene_member.in_promotion = false;
-                        bene_member
-                            .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-                    }
-                    ProposalType::Withdraw { .. } => {
-                        let account_iter = &mut remaining_accounts.iter();
-                        let bene_member_info = next_account_info(account_iter)?;
-                        let mut bene_member: MemberAccount = AccountDeserialize::try_deserialize(
-                            &mut &bene_member_info.data.borrow()[..],
-                        )?;
-                        if bene_member.owner != proposal.beneficiary {
-                            return if cfg!(feature = "dev") {
-                                err!(GroupError::MismatchedBeneMember)
-                            } else {
-                                Err(GroupError::MismatchedBeneMember.into())
-                            };
-                        }
-                        bene_member.in_withdraw = false;
-                        bene_member
-                            .try_serialize(&mut &mut bene_member_info.data.borrow_mut()[..])?;
-                    }
-                    _ => {}
-                }
             }
         }
+
+        let proposal = &mut ctx.accounts.proposal;
+        if !matches!(proposal.status, ProposalStatus::Queued { .. }) {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalNotVetoable)
+            } else {
+                Err(GroupError::ProposalNotVetoable.into())
+            };
+        }
+
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        let mut bene_member = load_bene_member(proposal, &bene_member_info, ctx.program_id)?;
+        // a closed member holds no locks, only those on the group are left
+        let mut closed = MemberAccount::default();
+        let now = ctx.accounts.clock.unix_timestamp;
+        let group = &mut ctx.accounts.group.load_mut()?;
+        proposal.release_locks(group, bene_member.as_deref_mut().unwrap_or(&mut closed));
+        if let Some(bene_member) = bene_member {
+            bene_member.exit(ctx.program_id)?;
+        }
+        proposal.status = ProposalStatus::Vetoed {
+            time: now,
+            by: authority,
+        };
+
+        emit!(VetoProposalEvent {
+            ptype: proposal.proposal_type.clone().into(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal.key(),
+            by: authority,
+            time: now,
+            label: "VetoProposal".to_string(),
+        });
         Ok(())
     }
 
-    pub fn close_signature(ctx: Context<CloseSignature>) -> Result<()> {
-        if let Ok(proposal) = <ProposalAccount as AnchorDeserialize>::deserialize(
-            &mut &ctx.accounts.proposal.try_borrow_data()?[..],
-        ) {
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        if !proposal.is_progressing() && !proposal.is_draft() {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalAlreadyFinished)
+            } else {
+                Err(GroupError::ProposalAlreadyFinished.into())
+            };
+        }
+
+        let now = ctx.accounts.clock.unix_timestamp;
+        let draft = proposal.is_draft();
+        if let ProposalType::Signal { options, metadata } = proposal.proposal_type {
+            if !draft {
+                proposal.status = ProposalStatus::Closed { time: now };
+
+                emit!(SignalResultEvent {
+                    submitter: proposal.submitter,
+                    group: proposal.group,
+                    proposal: proposal.key(),
+                    metadata,
+                    options,
+                    counts: proposal.choices,
+                    time: now,
+                    label: "SignalResult".to_string(),
+                });
+                return Ok(());
+            }
+        }
+
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        let mut bene_member = load_bene_member(proposal, &bene_member_info, ctx.program_id)?;
+        // a closed member holds no locks, only those on the group are left
+        let mut closed = MemberAccount::default();
+        let member = bene_member.as_deref_mut().unwrap_or(&mut closed);
+        let group = &mut ctx.accounts.group.load_mut()?;
+        // drafts never reached their sponsors and expire untallied
+        let tallied = if draft { None } else { proposal.tally(group, true) };
+        let result = match tallied {
+            Some(approved) => proposal.resolve(approved, now, group, member),
+            None => {
+                proposal.release_locks(group, member);
+                proposal.status = ProposalStatus::Expired { time: now };
+                ProposalResult::Expired
+            }
+        };
+        if let Some(bene_member) = bene_member {
+            bene_member.exit(ctx.program_id)?;
+        }
+
+        emit!(ExecuteProposalEvent {
+            ptype: proposal.proposal_type.clone().into(),
+            submitter: proposal.submitter,
+            beneficiary: proposal.beneficiary,
+            group: proposal.group,
+            proposal: proposal.key(),
+            result,
+            label: "ExecuteProposal".to_string(),
+        });
+        Ok(())
+    }
+
+    pub fn close_signature<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseSignature<'info>>,
+    ) -> Result<()> {
+        let proposal_info = &ctx.accounts.proposal;
+        if proposal_info.owner != ctx.program_id {
+            // proposal already closed
+            return Ok(());
+        }
+        if let Ok(mut proposal) =
+            ProposalAccount::try_deserialize(&mut &proposal_info.try_borrow_data()?[..])
+        {
             if let ProposalStatus::Progressing | ProposalStatus::Updated { .. } = proposal.status {
-                if proposal.deadline > ctx.accounts.clock.unix_timestamp {
+                if proposal.voting_end() > ctx.accounts.clock.unix_timestamp {
                     return if cfg!(feature = "dev") {
                         err!(GroupError::NotAllowedToClose)
                     } else {
@@ -753,6 +2339,87 @@ pub mod user_group {
                     };
                 }
             }
+
+            let amount = ctx.accounts.signature.amount;
+            if proposal.lock == LockMode::Escrow && amount > 0 {
+                let remaining_accounts = ctx.remaining_accounts;
+                let account_iter = &mut remaining_accounts.iter();
+                let escrow_token_account_info = next_account_info(account_iter)?;
+                let user_token_account_info = next_account_info(account_iter)?;
+                let token_program_info = next_account_info(account_iter)?;
+                if token_program_info.key() != token::ID {
+                    return Err(ProgramError::IncorrectProgramId.into());
+                }
+                is_proposal_escrow(
+                    &proposal_info.key(),
+                    ProposalAccount::VOTE_ESCROW_SEEDS,
+                    escrow_token_account_info,
+                    ctx.program_id,
+                )?;
+                let escrow_token = TokenAccount::try_deserialize(
+                    &mut &escrow_token_account_info.data.borrow()[..],
+                )?;
+                if escrow_token.mint != ctx.accounts.mint.key() {
+                    return if cfg!(feature = "dev") {
+                        err!(GroupError::MismatchedToken)
+                    } else {
+                        Err(GroupError::MismatchedToken.into())
+                    };
+                }
+                if ctx.accounts.signature.is_revealed() {
+                    let user_token = TokenAccount::try_deserialize(
+                        &mut &user_token_account_info.data.borrow()[..],
+                    )?;
+                    if user_token.owner != ctx.accounts.signature.signer
+                        || user_token.mint != escrow_token.mint
+                    {
+                        return if cfg!(feature = "dev") {
+                            err!(GroupError::WrongReceiverTokenAccount)
+                        } else {
+                            Err(GroupError::WrongReceiverTokenAccount.into())
+                        };
+                    }
+                }
+
+                let seeds = &[
+                    proposal.group.as_ref(),
+                    &proposal.index.to_le_bytes(),
+                    ProposalAccount::SEEDS,
+                    &[proposal.seed],
+                ];
+                let signer = &[&seeds[..]];
+                if ctx.accounts.signature.is_revealed() {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program_info.clone(),
+                            Transfer {
+                                from: escrow_token_account_info.clone(),
+                                to: user_token_account_info.clone(),
+                                authority: proposal_info.clone(),
+                            },
+                            signer,
+                        ),
+                        amount,
+                    )?;
+                } else {
+                    // unrevealed commitment forfeits its escrowed amount
+                    token::burn(
+                        CpiContext::new_with_signer(
+                            token_program_info.clone(),
+                            Burn {
+                                mint: ctx.accounts.mint.to_account_info(),
+                                from: escrow_token_account_info.clone(),
+                                authority: proposal_info.clone(),
+                            },
+                            signer,
+                        ),
+                        amount,
+                    )?;
+                }
+
+                proposal.escrowed -= amount;
+                proposal.try_serialize(&mut &mut proposal_info.data.borrow_mut()[..])?;
+            }
         }
 
         Ok(())
@@ -760,6 +2427,8 @@ pub mod user_group {
 
     #[access_control(is_proposal_allow_close(&ctx.accounts.proposal, ctx.accounts.clock.unix_timestamp))]
     pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        load_bene_member(&ctx.accounts.proposal, &bene_member_info, ctx.program_id)?;
         Ok(())
     }
 
@@ -778,11 +2447,23 @@ pub mod user_group {
     }
 
     pub fn reset_member(ctx: Context<ResetMember>) -> Result<()> {
-        let member = &mut ctx.accounts.bene_member;
         let proposal = &ctx.accounts.proposal;
+        let bene_member_info = ctx.accounts.bene_member.to_account_info();
+        // a closed member holds no locks
+        let mut member = match load_bene_member(proposal, &bene_member_info, ctx.program_id)? {
+            Some(member) => member,
+            None => return Ok(()),
+        };
+        if !member.in_promotion && !member.in_withdraw {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MemberNotInProposal)
+            } else {
+                Err(GroupError::MemberNotInProposal.into())
+            };
+        }
         use ProposalType::*;
         match proposal.proposal_type {
-            Upgrade | Downgrade | ReElection => {
+            Upgrade | Downgrade | ReElection | Remove => {
                 member.in_promotion = false;
             }
             Withdraw { .. } => {
@@ -790,6 +2471,7 @@ pub mod user_group {
             }
             _ => {}
         }
+        member.exit(ctx.program_id)?;
 
         msg!("Succeeded reset member status by proposal");
         Ok(())
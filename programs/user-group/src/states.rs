@@ -1,11 +1,23 @@
 mod admin;
+mod delegation;
+mod election;
 mod group;
+mod instruction_buffer;
+mod invite;
+mod join_request;
 mod member;
 mod proposal;
 mod signature;
+mod sponsorship;
 
 pub use admin::*;
+pub use delegation::*;
+pub use election::*;
 pub use group::*;
+pub use instruction_buffer::*;
+pub use invite::*;
+pub use join_request::*;
 pub use member::*;
 pub use proposal::*;
 pub use signature::*;
+pub use sponsorship::*;
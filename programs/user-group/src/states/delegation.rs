@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+#[account]
+#[derive(Debug, Default)]
+pub struct DelegationAccount {
+    pub group: Pubkey,
+    pub delegator: Pubkey, // delegator member account
+    pub delegate: Pubkey,  // delegate member account
+    pub owner: Pubkey,
+    pub created_at: i64,
+}
+impl DelegationAccount {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_delegation_size() {
+        assert_eq!(DelegationAccount::LEN, std::mem::size_of::<DelegationAccount>());
+    }
+}
@@ -0,0 +1,15 @@
+use crate::prelude::*;
+
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct ElectionAccount {
+    pub proposal: Pubkey,
+    pub candidates: Vec<Pubkey>, // candidate member accounts, in ballot index order
+}
+impl ElectionAccount {
+    pub const SEEDS: &'static [u8] = b"election";
+
+    pub fn space(candidates: usize) -> usize {
+        32 + 4 + 32 * candidates
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
 #[repr(C)]
@@ -19,7 +20,264 @@ impl ParticipateRate {
             .checked_div(self.denominator as u64)
             .unwrap_or(u64::MAX)
     }
+    // whether `part` is strictly more than this rate of `total`
+    pub fn is_exceeded_by(&self, part: u64, total: u64) -> bool {
+        part as u128 * self.denominator as u128 > total as u128 * self.numerator as u128
+    }
+    #[inline(always)]
+    pub fn is_valid_threshold(&self) -> bool {
+        self.numerator < self.denominator
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct ApprovalThresholds {
+    pub upgrade: ParticipateRate,
+    pub downgrade: ParticipateRate,
+    pub update_group: ParticipateRate,
+    pub re_election: ParticipateRate,
+    pub withdraw: ParticipateRate,
+    pub execute: ParticipateRate,
+    pub election: ParticipateRate,
+    pub remove: ParticipateRate,
+}
+impl Default for ApprovalThresholds {
+    fn default() -> Self {
+        let majority = ParticipateRate::new(1, 2);
+        Self {
+            upgrade: majority,
+            downgrade: majority,
+            update_group: majority,
+            re_election: majority,
+            withdraw: majority,
+            execute: majority,
+            election: majority,
+            remove: majority,
+        }
+    }
+}
+impl ApprovalThresholds {
+    pub fn get(&self, proposal_type: &ProposalType) -> ParticipateRate {
+        match proposal_type {
+            ProposalType::Upgrade => self.upgrade,
+            ProposalType::Downgrade => self.downgrade,
+            ProposalType::UpdateGroup { .. } => self.update_group,
+            ProposalType::ReElection => self.re_election,
+            ProposalType::Withdraw { .. } => self.withdraw,
+            ProposalType::Execute { .. } => self.execute,
+            ProposalType::Election { .. } => self.election,
+            ProposalType::Remove => self.remove,
+            // non-binding, never tallied
+            ProposalType::Signal { .. } => ParticipateRate::default(),
+        }
+    }
+    pub fn is_valid(&self) -> bool {
+        self.upgrade.is_valid_threshold()
+            && self.downgrade.is_valid_threshold()
+            && self.update_group.is_valid_threshold()
+            && self.re_election.is_valid_threshold()
+            && self.withdraw.is_valid_threshold()
+            && self.execute.is_valid_threshold()
+            && self.election.is_valid_threshold()
+            && self.remove.is_valid_threshold()
+    }
+}
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum VoteWeight {
+    // one member, one vote
+    #[default]
+    Headcount,
+    // vote weight is the amount of token burned
+    Token,
+    // vote weight is the integer square root of the amount of token burned
+    Quadratic,
+}
+impl VoteWeight {
+    // effective weight of `amount` token locked by a vote
+    pub fn of(&self, amount: u64) -> u64 {
+        match self {
+            VoteWeight::Quadratic => isqrt(amount),
+            _ => amount,
+        }
+    }
+    // weight a vote adds to each of its choices
+    pub fn of_choice(&self, weight: u64) -> u64 {
+        match self {
+            VoteWeight::Headcount => 1,
+            _ => weight,
+        }
+    }
+}
+
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2 + 1;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum QuorumKind {
+    // `rate` of all users must participate
+    #[default]
+    Headcount,
+    // at least `quorum_weight` token must be burned
+    Weight,
+}
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum LockMode {
+    // voting token is burned
+    #[default]
+    Burn,
+    // voting token is escrowed by the proposal and refunded on `close_signature`
+    Escrow,
+    // voting token is sent to the group vault
+    Treasury,
+}
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum TallyMode {
+    // resolved by the vote reaching quorum
+    #[default]
+    EarlyQuorum,
+    // resolved by `finalize_proposal` after deadline
+    FullPeriod,
+    // resolved early only if outstanding votes can not change the result
+    Decisive,
+}
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum SlashMode {
+    // slashed bond is burned
+    #[default]
+    Burn,
+    // slashed bond is sent to the group vault
+    Treasury,
 }
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum JoinPolicy {
+    // anyone joins directly
+    #[default]
+    Open,
+    // a join request is approved by any manager
+    Manager,
+    // a join request is approved by `rate` of managers
+    Vote,
+}
+
+#[repr(u8)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum TokenGate {
+    // no token required
+    #[default]
+    None,
+    // joining user holds at least `gate_amount` of `gate_mint`
+    Balance,
+    // `gate_amount` of `gate_mint` is escrowed by the group until exit
+    Stake,
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct MembershipConfig {
+    pub gate_amount: u64,
+    pub gate_mint: Pubkey,
+    // merkle root of allowlisted users, zero for no allowlist
+    pub allowlist: [u8; 32],
+    pub policy: JoinPolicy,
+    pub gate: TokenGate,
+    // share of managers deciding a join request under JoinPolicy::Vote
+    pub threshold: ParticipateRate,
+    padding: [u8; 4],
+}
+impl MembershipConfig {
+    pub const STAKE_SEEDS: &'static [u8] = b"stake";
+
+    pub fn is_valid(&self) -> bool {
+        (self.gate == TokenGate::None
+            || (self.gate_amount > 0 && self.gate_mint != Pubkey::default()))
+            && (self.policy != JoinPolicy::Vote || self.threshold.is_valid_threshold())
+    }
+
+    #[inline(always)]
+    pub fn has_allowlist(&self) -> bool {
+        self.allowlist != [0u8; 32]
+    }
+
+    pub fn allowlist_leaf(user: &Pubkey, position: &Option<Position>) -> [u8; 32] {
+        match position {
+            Some(position) => hashv(&[user.as_ref(), &[position.clone() as u8]]).to_bytes(),
+            None => hashv(&[user.as_ref()]).to_bytes(),
+        }
+    }
+
+    // `proof` hashes sorted pairs from the leaf of `user` up to the allowlist root
+    pub fn is_allowlisted(
+        &self,
+        user: &Pubkey,
+        position: &Option<Position>,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        let mut node = Self::allowlist_leaf(user, position);
+        for sibling in proof {
+            node = if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+        node == self.allowlist
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+#[repr(C)]
+pub struct VoteConfig {
+    pub quorum_weight: u64,
+    // delay in seconds between passing and executing a proposal
+    pub timelock: i64,
+    // length in seconds of the reveal window after deadline, zero for open voting
+    pub reveal_period: i64,
+    // governance token escrowed by the submitter of a proposal, zero for no bond
+    pub bond: u64,
+    // length in seconds a draft proposal may wait for sponsors before expiring
+    pub draft_period: i64,
+    pub weight: VoteWeight,
+    pub quorum: QuorumKind,
+    pub lock: LockMode,
+    pub tally: TallyMode,
+    pub slash: SlashMode,
+    // co-sponsors required before voting opens, zero to open immediately
+    pub sponsors: u8,
+    pub thresholds: ApprovalThresholds,
+    padding: [u8; 2],
+}
+impl VoteConfig {
+    pub fn is_valid(&self) -> bool {
+        !(self.quorum == QuorumKind::Weight && self.quorum_weight == 0)
+            && self.timelock >= 0
+            && self.reveal_period >= 0
+            && self.draft_period >= 0
+            && !(self.sponsors > 0 && self.draft_period == 0)
+            && self.thresholds.is_valid()
+    }
+}
+
 #[account(zero_copy)]
 #[derive(Debug, Default, AnchorSerialize, AnchorDeserialize)]
 pub struct GroupAccount {
@@ -36,6 +294,10 @@ pub struct GroupAccount {
     pub index: u32,
     pub sponsor: Pubkey,
     pub admin: Pubkey,
+    // members and join requests holding a stake of the gate mint
+    pub stakes: u32,
+    pub config: VoteConfig,
+    pub membership: MembershipConfig,
 }
 impl GroupAccount {
     pub const SEEDS: &'static [u8] = b"group";
@@ -51,7 +313,44 @@ impl GroupAccount {
         + 4
         + 4
         + 32
-        + 32;
+        + 32
+        + 4
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 16
+        + 2 // padding
+        + 8
+        + 32
+        + 32
+        + 1
+        + 1
+        + 6; // padding
+
+    /// Count a new member in, the first one of the group joins as manager
+    /// unless the allowlist preassigned a position.
+    pub fn admit(&mut self, position: Option<Position>) -> Position {
+        let position = position.unwrap_or(
+            if self.current_member == 0 && self.current_manager == 0 {
+                Position::Manager
+            } else {
+                Position::Member
+            },
+        );
+        match position {
+            Position::Member => self.current_member += 1,
+            Position::Manager => self.current_manager += 1,
+        }
+        position
+    }
 
     #[inline(always)]
     pub fn manager_number(&self) -> u64 {
@@ -69,6 +368,25 @@ impl GroupAccount {
     pub fn hence(&self) -> u64 {
         self.rate.calc_number(self.total_user())
     }
+    #[inline]
+    pub fn eligible_voters(&self, proposal_type: &ProposalType) -> u64 {
+        let mut voters = 0;
+        if proposal_type.is_voter(&Position::Manager) {
+            voters += self.manager_number();
+        }
+        if proposal_type.is_voter(&Position::Member) {
+            voters += self.member_number();
+        }
+        voters
+    }
+    // `voters` is the eligible voter count snapshotted by the proposal
+    #[inline]
+    pub fn is_quorum_reached(&self, voters: u64, participated: u64, participated_weight: u64) -> bool {
+        match self.config.quorum {
+            QuorumKind::Headcount => participated >= self.rate.calc_number(voters),
+            QuorumKind::Weight => participated_weight >= self.config.quorum_weight,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -79,4 +397,183 @@ mod tests {
     pub fn test_group_size() {
         assert_eq!(std::mem::size_of::<GroupAccount>(), GroupAccount::LEN);
     }
+
+    #[test]
+    pub fn test_quorum() {
+        let mut group = GroupAccount::default();
+        group.rate = ParticipateRate::new(50, 100);
+        group.current_manager = 1;
+        group.current_member = 3;
+        assert!(!group.is_quorum_reached(group.total_user(), 1, 1_000_000));
+        assert!(group.is_quorum_reached(group.total_user(), 2, 0));
+        // members joined after the snapshot do not move the quorum
+        group.current_member = 7;
+        assert!(group.is_quorum_reached(4, 2, 0));
+
+        group.config = VoteConfig {
+            quorum_weight: 1_000_000,
+            timelock: 0,
+            reveal_period: 0,
+            bond: 0,
+            draft_period: 0,
+            weight: VoteWeight::Token,
+            quorum: QuorumKind::Weight,
+            lock: LockMode::Burn,
+            tally: TallyMode::EarlyQuorum,
+            slash: SlashMode::Burn,
+            sponsors: 0,
+            thresholds: ApprovalThresholds::default(),
+            ..VoteConfig::default()
+        };
+        assert!(!group.is_quorum_reached(4, 4, 999_999));
+        assert!(group.is_quorum_reached(4, 1, 1_000_000));
+    }
+
+    #[test]
+    pub fn test_vote_config() {
+        assert!(VoteConfig::default().is_valid());
+        let config = VoteConfig {
+            quorum: QuorumKind::Weight,
+            ..VoteConfig::default()
+        };
+        assert!(!config.is_valid());
+
+        let mut config = VoteConfig::default();
+        config.thresholds.withdraw = ParticipateRate::new(3, 3);
+        assert!(!config.is_valid());
+        config.thresholds.withdraw = ParticipateRate::new(2, 0);
+        assert!(!config.is_valid());
+
+        let config = VoteConfig {
+            timelock: -1,
+            ..VoteConfig::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = VoteConfig {
+            reveal_period: -1,
+            ..VoteConfig::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = VoteConfig {
+            sponsors: 2,
+            ..VoteConfig::default()
+        };
+        assert!(!config.is_valid());
+        let config = VoteConfig {
+            sponsors: 2,
+            draft_period: 86400,
+            ..VoteConfig::default()
+        };
+        assert!(config.is_valid());
+    }
+
+    #[test]
+    pub fn test_membership_config() {
+        assert!(MembershipConfig::default().is_valid());
+        let membership = MembershipConfig {
+            gate: TokenGate::Stake,
+            gate_amount: 100,
+            ..MembershipConfig::default()
+        };
+        assert!(!membership.is_valid());
+        let membership = MembershipConfig {
+            gate_mint: Pubkey::new_unique(),
+            ..membership
+        };
+        assert!(membership.is_valid());
+        let membership = MembershipConfig {
+            gate: TokenGate::Balance,
+            gate_amount: 0,
+            ..membership
+        };
+        assert!(!membership.is_valid());
+
+        // the join vote needs its own threshold, the group rate defaults to 100/100
+        let membership = MembershipConfig {
+            policy: JoinPolicy::Vote,
+            ..MembershipConfig::default()
+        };
+        assert!(!membership.is_valid());
+        let membership = MembershipConfig {
+            threshold: ParticipateRate::new(100, 100),
+            ..membership
+        };
+        assert!(!membership.is_valid());
+        let membership = MembershipConfig {
+            threshold: ParticipateRate::new(1, 2),
+            ..membership
+        };
+        assert!(membership.is_valid());
+    }
+
+    #[test]
+    pub fn test_allowlist() {
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let manager = Some(Position::Manager);
+        let alice_leaf = MembershipConfig::allowlist_leaf(&alice, &manager);
+        let bob_leaf = MembershipConfig::allowlist_leaf(&bob, &None);
+        let root = if alice_leaf <= bob_leaf {
+            hashv(&[&alice_leaf, &bob_leaf])
+        } else {
+            hashv(&[&bob_leaf, &alice_leaf])
+        };
+        let membership = MembershipConfig {
+            allowlist: root.to_bytes(),
+            ..MembershipConfig::default()
+        };
+        assert!(membership.has_allowlist());
+        assert!(membership.is_allowlisted(&alice, &manager, &[bob_leaf]));
+        assert!(membership.is_allowlisted(&bob, &None, &[alice_leaf]));
+        assert!(!membership.is_allowlisted(&alice, &None, &[bob_leaf]));
+        assert!(!membership.is_allowlisted(&bob, &manager, &[alice_leaf]));
+        assert!(!membership.is_allowlisted(&Pubkey::new_unique(), &None, &[alice_leaf]));
+
+        let mut group = GroupAccount::default();
+        assert_eq!(group.admit(None), Position::Manager);
+        assert_eq!(group.admit(None), Position::Member);
+        assert_eq!(group.admit(manager), Position::Manager);
+        assert_eq!((group.current_manager, group.current_member), (2, 1));
+    }
+
+    #[test]
+    pub fn test_quadratic_weight() {
+        let quadratic = VoteWeight::Quadratic;
+        assert_eq!(quadratic.of(0), 0);
+        assert_eq!(quadratic.of(1), 1);
+        assert_eq!(quadratic.of(99), 9);
+        assert_eq!(quadratic.of(100), 10);
+        assert_eq!(quadratic.of(u64::MAX), u32::MAX as u64);
+        assert_eq!(VoteWeight::Token.of(100), 100);
+        assert_eq!(VoteWeight::Headcount.of_choice(100), 1);
+        assert_eq!(quadratic.of_choice(10), 10);
+    }
+
+    #[test]
+    pub fn test_threshold() {
+        let majority = ParticipateRate::new(1, 2);
+        assert!(majority.is_exceeded_by(2, 3));
+        assert!(!majority.is_exceeded_by(1, 2));
+        assert!(!majority.is_exceeded_by(0, 0));
+
+        let supermajority = ParticipateRate::new(2, 3);
+        assert!(supermajority.is_exceeded_by(3, 4));
+        assert!(!supermajority.is_exceeded_by(2, 3));
+        assert!(!supermajority.is_exceeded_by(u64::MAX / 2, u64::MAX));
+
+        let thresholds = ApprovalThresholds {
+            withdraw: supermajority,
+            ..ApprovalThresholds::default()
+        };
+        let withdraw = ProposalType::Withdraw {
+            mint: Pubkey::default(),
+            receiver: Pubkey::default(),
+            amount: 1,
+        };
+        assert_eq!(thresholds.get(&withdraw), supermajority);
+        assert_eq!(thresholds.get(&ProposalType::Upgrade), majority);
+        assert_eq!(thresholds.get(&ProposalType::Remove), majority);
+    }
 }
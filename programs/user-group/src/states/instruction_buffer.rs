@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+
+#[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub struct SerializedAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+impl SerializedAccountMeta {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+impl From<&SerializedAccountMeta> for AccountMeta {
+    fn from(meta: &SerializedAccountMeta) -> Self {
+        AccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub struct SerializedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<SerializedAccountMeta>,
+    pub data: Vec<u8>,
+}
+impl SerializedInstruction {
+    pub fn space(&self) -> usize {
+        32 + 4 + self.accounts.len() * SerializedAccountMeta::LEN + 4 + self.data.len()
+    }
+}
+impl From<&SerializedInstruction> for Instruction {
+    fn from(ix: &SerializedInstruction) -> Self {
+        Instruction {
+            program_id: ix.program_id,
+            accounts: ix.accounts.iter().map(Into::into).collect(),
+            data: ix.data.clone(),
+        }
+    }
+}
+
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct InstructionBufferAccount {
+    pub proposal: Pubkey,
+    pub instructions: Vec<SerializedInstruction>,
+}
+impl InstructionBufferAccount {
+    pub const SEEDS: &'static [u8] = b"instructions";
+
+    pub fn space(instructions: &[SerializedInstruction]) -> usize {
+        32 + 4 + instructions.iter().map(|ix| ix.space()).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_instruction_buffer_space() {
+        let buffer = InstructionBufferAccount {
+            proposal: Pubkey::new_unique(),
+            instructions: vec![
+                SerializedInstruction {
+                    program_id: Pubkey::new_unique(),
+                    accounts: vec![
+                        SerializedAccountMeta {
+                            pubkey: Pubkey::new_unique(),
+                            is_signer: true,
+                            is_writable: false,
+                        },
+                        SerializedAccountMeta {
+                            pubkey: Pubkey::new_unique(),
+                            is_signer: false,
+                            is_writable: true,
+                        },
+                    ],
+                    data: vec![1, 2, 3],
+                },
+                SerializedInstruction {
+                    program_id: Pubkey::new_unique(),
+                    accounts: vec![],
+                    data: vec![],
+                },
+            ],
+        };
+        let data = AnchorSerialize::try_to_vec(&buffer).unwrap();
+        assert_eq!(
+            data.len(),
+            InstructionBufferAccount::space(&buffer.instructions)
+        );
+
+        let ix: Instruction = (&buffer.instructions[0]).into();
+        assert_eq!(ix.accounts.len(), 2);
+        assert!(ix.accounts[0].is_signer);
+        assert!(ix.accounts[1].is_writable);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+#[account]
+#[derive(Debug, Default)]
+pub struct InviteAccount {
+    pub group: Pubkey,
+    pub manager: Pubkey, // issuing manager member account
+    pub creator: Pubkey,
+    pub code_hash: [u8; 32],
+    pub remaining_uses: u32,
+    pub expire_at: i64,
+    pub created_at: i64,
+}
+impl InviteAccount {
+    pub const SEEDS: &'static [u8] = b"invite";
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 4 + 8 + 8 + 4; // padding
+
+    #[inline(always)]
+    pub fn is_code(&self, code: &[u8]) -> bool {
+        hash(code).to_bytes() == self.code_hash
+    }
+
+    /// Consume one use of the invite, false if it is used up.
+    pub fn redeem(&mut self) -> bool {
+        if self.remaining_uses == 0 {
+            return false;
+        }
+        self.remaining_uses -= 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_invite_size() {
+        assert_eq!(InviteAccount::LEN, std::mem::size_of::<InviteAccount>());
+    }
+
+    #[test]
+    pub fn test_invite_redeem() {
+        let mut invite = InviteAccount {
+            code_hash: hash(b"secret").to_bytes(),
+            remaining_uses: 2,
+            ..InviteAccount::default()
+        };
+        assert!(invite.is_code(b"secret"));
+        assert!(!invite.is_code(b"secrets"));
+        assert!(invite.redeem());
+        assert!(invite.redeem());
+        assert!(!invite.redeem());
+        assert_eq!(invite.remaining_uses, 0);
+    }
+}
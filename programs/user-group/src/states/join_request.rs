@@ -0,0 +1,101 @@
+use crate::prelude::*;
+
+#[account]
+#[derive(Debug, Default)]
+pub struct JoinRequestAccount {
+    pub group: Pubkey,
+    pub user: Pubkey,
+    pub funder: Pubkey,
+    pub created_at: i64,
+    pub approvals: u32,
+    pub rejections: u32,
+    // gate token escrowed by the group, refunded if rejected
+    pub staked: u64,
+    // mint of the stake, handed over to the member once approved
+    pub stake_mint: Pubkey,
+    // `Some(approved)` once decided, then closed by `close_join_request`
+    pub decision: Option<bool>,
+}
+impl JoinRequestAccount {
+    pub const SEEDS: &'static [u8] = b"join_request";
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 4 + 4 + 8 + 32 + 2 + 6; // padding
+
+    /// `Some(true)` once approved, `Some(false)` once rejected, `None` while pending.
+    pub fn decide(&self, policy: JoinPolicy, rate: &ParticipateRate, managers: u64) -> Option<bool> {
+        match policy {
+            JoinPolicy::Vote => {
+                if rate.is_exceeded_by(self.approvals as u64, managers) {
+                    Some(true)
+                } else if !rate.is_exceeded_by(
+                    managers.saturating_sub(self.rejections as u64),
+                    managers,
+                ) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            // a single manager decides
+            _ => Some(self.approvals > 0),
+        }
+    }
+}
+
+#[account]
+#[derive(Debug, Default)]
+pub struct JoinReviewAccount {
+    pub request: Pubkey,
+    pub reviewer: Pubkey, // reviewer member account
+    pub created_at: i64,
+    pub approve: bool,
+}
+impl JoinReviewAccount {
+    pub const SEEDS: &'static [u8] = b"review";
+    pub const LEN: usize = 32 + 32 + 8 + 1 + 7; // padding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_join_request_size() {
+        assert_eq!(JoinRequestAccount::LEN, std::mem::size_of::<JoinRequestAccount>());
+        assert_eq!(JoinReviewAccount::LEN, std::mem::size_of::<JoinReviewAccount>());
+    }
+
+    #[test]
+    pub fn test_join_request_decide() {
+        let rate = ParticipateRate::new(1, 2);
+        let mut request = JoinRequestAccount {
+            rejections: 1,
+            ..JoinRequestAccount::default()
+        };
+        assert_eq!(request.decide(JoinPolicy::Manager, &rate, 3), Some(false));
+        assert_eq!(request.decide(JoinPolicy::Vote, &rate, 3), None);
+        request.rejections = 2;
+        assert_eq!(request.decide(JoinPolicy::Vote, &rate, 3), Some(false));
+
+        let mut request = JoinRequestAccount {
+            approvals: 1,
+            ..JoinRequestAccount::default()
+        };
+        assert_eq!(request.decide(JoinPolicy::Manager, &rate, 3), Some(true));
+        assert_eq!(request.decide(JoinPolicy::Vote, &rate, 3), None);
+        request.approvals = 2;
+        assert_eq!(request.decide(JoinPolicy::Vote, &rate, 3), Some(true));
+    }
+
+    #[test]
+    pub fn test_join_request_decide_default_rate() {
+        // the rate of a new group can never be exceeded, the join vote uses its own threshold
+        let group_rate = ParticipateRate::new(100, 100);
+        let threshold = ParticipateRate::new(1, 2);
+        let request = JoinRequestAccount {
+            approvals: 3,
+            ..JoinRequestAccount::default()
+        };
+        assert_eq!(request.decide(JoinPolicy::Vote, &group_rate, 3), Some(false));
+        assert_eq!(request.decide(JoinPolicy::Vote, &threshold, 3), Some(true));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use anchor_lang::Discriminator;
 
 #[repr(u8)]
 #[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
@@ -29,12 +30,64 @@ pub struct MemberAccount {
     pub position: Position,
     pub in_promotion: bool,
     pub in_withdraw: bool,
+    // allowed to veto proposals of the group
+    pub council: bool,
     pub group: Pubkey,
     pub funder: Pubkey,
     pub owner: Pubkey,
+    pub joined_at: i64,
+    // time the current position was taken
+    pub positioned_at: i64,
+    // gate token escrowed by the group, returned on exit
+    pub staked: u64,
+    // mint of the stake, its escrow outlives later gate changes
+    pub stake_mint: Pubkey,
+    // invite account and issuing manager member account, default when not invited
+    pub invite: Pubkey,
+    pub inviter: Pubkey,
 }
 impl MemberAccount {
     pub const SEEDS: &'static [u8] = b"member";
+    pub const LEN: usize = 1 + 1 + 1 + 1 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 32 + 4; // padding
+
+    // member account created before council, join time, stake and invites were added
+    pub fn try_from_legacy(data: &[u8]) -> Result<Self> {
+        if data.len() != 8 + LegacyMemberAccount::LEN {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::MemberAlreadyMigrated)
+            } else {
+                Err(GroupError::MemberAlreadyMigrated.into())
+            };
+        }
+        if data[..8] != MemberAccount::discriminator() {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        let legacy = LegacyMemberAccount::deserialize(&mut &data[8..])?;
+        Ok(MemberAccount {
+            position: legacy.position,
+            in_promotion: legacy.in_promotion,
+            in_withdraw: legacy.in_withdraw,
+            group: legacy.group,
+            funder: legacy.funder,
+            owner: legacy.owner,
+            // joined before any snapshot taken after the migration
+            joined_at: 0,
+            positioned_at: 0,
+            ..MemberAccount::default()
+        })
+    }
+}
+
+#[derive(Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyMemberAccount {
+    pub position: Position,
+    pub in_promotion: bool,
+    pub in_withdraw: bool,
+    pub group: Pubkey,
+    pub funder: Pubkey,
+    pub owner: Pubkey,
+}
+impl LegacyMemberAccount {
     pub const LEN: usize = 1 + 1 + 1 + 32 + 32 + 32;
 }
 
@@ -46,4 +99,41 @@ mod tests {
     pub fn test_member_size() {
         assert_eq!(MemberAccount::LEN, std::mem::size_of::<MemberAccount>());
     }
+
+    #[test]
+    pub fn test_migrate_legacy_member() {
+        let legacy = LegacyMemberAccount {
+            position: Position::Manager,
+            in_promotion: false,
+            in_withdraw: true,
+            group: Pubkey::new_unique(),
+            funder: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+        };
+        let mut data = MemberAccount::discriminator().to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), 8 + LegacyMemberAccount::LEN);
+        // the current layout no longer reads an old account
+        assert!(MemberAccount::try_deserialize(&mut &data[..]).is_err());
+
+        let member = MemberAccount::try_from_legacy(&data).unwrap();
+        assert_eq!(member.position, Position::Manager);
+        assert!(!member.in_promotion);
+        assert!(member.in_withdraw);
+        assert!(!member.council);
+        assert_eq!(member.group, legacy.group);
+        assert_eq!(member.funder, legacy.funder);
+        assert_eq!(member.owner, legacy.owner);
+        assert_eq!(member.joined_at, 0);
+        assert_eq!(member.positioned_at, 0);
+        assert_eq!(member.staked, 0);
+        assert_eq!(member.stake_mint, Pubkey::default());
+        assert_eq!(member.invite, Pubkey::default());
+
+        let mut migrated = vec![0u8; 8 + MemberAccount::LEN];
+        member.try_serialize(&mut &mut migrated[..]).unwrap();
+        let member = MemberAccount::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(member.owner, legacy.owner);
+        assert!(MemberAccount::try_from_legacy(&migrated).is_err());
+    }
 }
@@ -1,5 +1,9 @@
 use crate::prelude::*;
 
+pub const MAX_CHOICES: usize = 8;
+pub const MAX_TITLE_LEN: usize = 64;
+pub const MAX_URI_LEN: usize = 200;
+
 #[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
 #[repr(C)]
 #[non_exhaustive]
@@ -16,19 +20,91 @@ pub enum ProposalType {
         receiver: Pubkey,
         amount: u64,
     },
+    // invoke `instructions` stored in the proposal's instruction buffer, signed by the group
+    Execute {
+        instructions: u8,
+    },
+    // promote the top `seats` of `candidates` stored in the proposal's election account
+    Election {
+        candidates: u8,
+        seats: u8,
+        ranked: bool,
+    },
+    // non-binding poll over `options`, `metadata` is the hash of the question
+    Signal {
+        options: u8,
+        metadata: [u8; 32],
+    },
+    // close the beneficiary's member account
+    Remove,
 }
 impl Default for ProposalType {
     fn default() -> Self {
         Self::Upgrade
     }
 }
+impl ProposalType {
+    // positions allowed to vote on such proposal
+    pub fn is_voter(&self, position: &Position) -> bool {
+        match self {
+            ProposalType::Withdraw { .. } => position.is_manager(),
+            _ => true,
+        }
+    }
+
+    // whether voting waits for an instruction buffer or election account
+    pub fn has_payload(&self) -> bool {
+        matches!(
+            self,
+            ProposalType::Execute { .. } | ProposalType::Election { .. }
+        )
+    }
+
+    // whether votes carry a ballot of choices
+    pub fn has_ballot(&self) -> bool {
+        matches!(
+            self,
+            ProposalType::Election { .. } | ProposalType::Signal { .. }
+        )
+    }
+
+    // whether `choices` is a valid ballot for `sign` on such proposal
+    pub fn is_valid_ballot(&self, sign: &SignType, choices: &[u8]) -> bool {
+        match self {
+            ProposalType::Election { candidates, .. } if *sign == SignType::Agreed => {
+                !choices.is_empty()
+                    && choices.len() <= *candidates as usize
+                    && choices.iter().enumerate().all(|(i, choice)| {
+                        *choice < *candidates && !choices[..i].contains(choice)
+                    })
+            }
+            ProposalType::Signal { options, .. } => match sign {
+                SignType::Agreed => choices.len() == 1 && choices[0] < *options,
+                SignType::Abstain => choices.is_empty(),
+                SignType::Denied => false,
+            },
+            _ => choices.is_empty(),
+        }
+    }
+}
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
 pub enum ProposalStatus {
     Progressing,
+    // passed and executed
     Passed { time: i64 },
     Rejected { time: i64 },
     Updated { time: i64 },
+    Expired { time: i64 },
+    // passed, executable after `eta`
+    Queued { eta: i64 },
+    Canceled { time: i64 },
+    // non-binding proposal finalized
+    Closed { time: i64 },
+    // stopped by an administrator or a council manager
+    Vetoed { time: i64, by: Pubkey },
+    // waiting for co-sponsors until `expire`
+    Draft { expire: i64 },
 }
 impl Default for ProposalStatus {
     fn default() -> Self {
@@ -44,20 +120,338 @@ pub struct ProposalAccount {
     pub group: Pubkey,
     pub positive: u32,
     pub negative: u32,
+    pub abstain: u32,
+    pub voters: u32,
+    pub positive_weight: u64,
+    pub negative_weight: u64,
+    pub abstain_weight: u64,
+    pub choices: [u64; MAX_CHOICES], // score of each candidate or option
     pub limit: u64,
     pub deadline: i64,
     pub revoke_timeout: i64,
     pub close_timeout: i64,
+    pub snapshot_at: i64,
+    pub reveal_period: i64, // commit-reveal voting if not zero
+    pub escrowed: u64,
+    pub bond: u64, // submitter bond held in the escrow, zero once settled
+    pub index: u32,
+    pub seed: u8,
+    pub lock: LockMode,
+    pub sponsors: u16,
+    pub payload_ready: bool, // false until the instruction buffer or election is created
+    pub voted: bool,         // set by the first counted vote, kept after a retraction
     pub proposal_type: ProposalType,
     pub status: ProposalStatus,
+    pub title: String,
+    pub uri: String, // off-chain description
+    pub content_hash: [u8; 32],
 }
 impl ProposalAccount {
     pub const SEEDS: &'static [u8] = b"proposal";
-    pub const LEN: usize = 32 + 32 + 32 + 32 + 4 + 4 + 8 + 8 + 8 + 8 + 80 + 16;
+    pub const VOTE_ESCROW_SEEDS: &'static [u8] = b"vote_escrow";
+    pub const BOND_ESCROW_SEEDS: &'static [u8] = b"bond_escrow";
+    pub const LEN: usize = 32
+        + 32
+        + 32
+        + 32
+        + 4
+        + 4
+        + 4
+        + 4
+        + 8
+        + 8
+        + 8
+        + 8 * MAX_CHOICES
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 4
+        + 1
+        + 1
+        + 2
+        + 1
+        + 1
+        + 6 // padding
+        + 80
+        + 48
+        + 4 + MAX_TITLE_LEN
+        + 4 + MAX_URI_LEN
+        + 32;
+
+    pub fn is_valid_metadata(title: &str, uri: &str) -> bool {
+        title.len() <= MAX_TITLE_LEN && uri.len() <= MAX_URI_LEN
+    }
+
+    // `Some(true)` if the bond goes back to the submitter, `Some(false)` if slashed
+    pub fn is_bond_refundable(&self) -> Option<bool> {
+        match self.status {
+            ProposalStatus::Passed { .. }
+            | ProposalStatus::Canceled { .. }
+            | ProposalStatus::Closed { .. } => Some(true),
+            ProposalStatus::Rejected { .. }
+            | ProposalStatus::Expired { .. }
+            | ProposalStatus::Vetoed { .. } => Some(false),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_period > 0
+    }
+
+    // votes are counted until deadline, or until the reveal window closes
+    #[inline(always)]
+    pub fn voting_end(&self) -> i64 {
+        self.deadline + self.reveal_period
+    }
+
+    // `voters` counts positions at the snapshot, a position taken later was not counted
+    // unless every position votes
+    pub fn counts_position_of(&self, member: &MemberAccount) -> bool {
+        member.positioned_at <= self.snapshot_at
+            || (self.proposal_type.is_voter(&Position::Manager)
+                && self.proposal_type.is_voter(&Position::Member))
+    }
 
     #[inline(always)]
     pub fn participated(&self) -> u64 {
-        (self.positive + self.negative) as u64
+        self.positive as u64 + self.negative as u64 + self.abstain as u64
+    }
+
+    pub fn add_vote(&mut self, sign: &SignType, weight: u64) {
+        self.voted = true;
+        match sign {
+            SignType::Agreed => {
+                self.positive += 1;
+                self.positive_weight = self.positive_weight.saturating_add(weight);
+            }
+            SignType::Denied => {
+                self.negative += 1;
+                self.negative_weight = self.negative_weight.saturating_add(weight);
+            }
+            SignType::Abstain => {
+                self.abstain += 1;
+                self.abstain_weight = self.abstain_weight.saturating_add(weight);
+            }
+        }
+    }
+
+    // `Some(approved)` once the result is final, `ended` if voting period is over
+    pub fn tally(&self, group: &GroupAccount, ended: bool) -> Option<bool> {
+        if let ProposalType::Signal { .. } = self.proposal_type {
+            return None;
+        }
+        if !group.is_quorum_reached(
+            self.voters as u64,
+            self.participated(),
+            self.participated_weight(),
+        ) {
+            return None;
+        }
+        let weight = group.config.weight;
+        let threshold = group.config.thresholds.get(&self.proposal_type);
+        let approved = self.is_approved(weight, &threshold);
+        match group.config.tally {
+            _ if ended => Some(approved),
+            TallyMode::EarlyQuorum => Some(approved),
+            TallyMode::FullPeriod => None,
+            TallyMode::Decisive => {
+                if self.is_decided(weight, &threshold) {
+                    Some(approved)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    // whether outstanding eligible votes can still change the result
+    pub fn is_decided(&self, weight: VoteWeight, threshold: &ParticipateRate) -> bool {
+        let outstanding = (self.voters as u64).saturating_sub(self.participated());
+        if outstanding == 0 {
+            return true;
+        }
+        match weight {
+            // outstanding token weight is unbounded
+            VoteWeight::Token | VoteWeight::Quadratic => false,
+            VoteWeight::Headcount => {
+                let positive = self.positive as u64;
+                let decided = positive + self.negative as u64;
+                threshold.is_exceeded_by(positive + outstanding, decided + outstanding)
+                    == threshold.is_exceeded_by(positive, decided + outstanding)
+            }
+        }
+    }
+
+    // moves a tallied proposal to its terminal or queued status
+    pub fn resolve(
+        &mut self,
+        approved: bool,
+        now: i64,
+        group: &mut GroupAccount,
+        bene_member: &mut MemberAccount,
+    ) -> ProposalResult {
+        if approved {
+            self.status = ProposalStatus::Queued {
+                eta: now + group.config.timelock,
+            };
+            ProposalResult::Queued
+        } else {
+            self.status = ProposalStatus::Rejected { time: now };
+            self.release_locks(group, bene_member);
+            ProposalResult::Rejected
+        }
+    }
+
+    pub fn add_choices(&mut self, choices: &[u8], weight: u64) {
+        for (rank, choice) in choices.iter().enumerate() {
+            let score = self.score(rank).saturating_mul(weight);
+            let total = &mut self.choices[*choice as usize];
+            *total = total.saturating_add(score);
+        }
+    }
+
+    pub fn remove_choices(&mut self, choices: &[u8], weight: u64) {
+        for (rank, choice) in choices.iter().enumerate() {
+            let score = self.score(rank).saturating_mul(weight);
+            let total = &mut self.choices[*choice as usize];
+            *total = total.saturating_sub(score);
+        }
+    }
+
+    // points for the choice at `rank` of a ballot, borda count when ranked
+    fn score(&self, rank: usize) -> u64 {
+        match self.proposal_type {
+            ProposalType::Election {
+                candidates,
+                ranked: true,
+                ..
+            } => (candidates as usize - rank) as u64,
+            _ => 1,
+        }
+    }
+
+    // candidate indexes elected by the current scores, best first
+    pub fn elected(&self) -> Vec<usize> {
+        match self.proposal_type {
+            ProposalType::Election {
+                candidates, seats, ..
+            } => {
+                let mut ranking: Vec<usize> = (0..candidates as usize)
+                    .filter(|i| self.choices[*i] > 0)
+                    .collect();
+                ranking.sort_by(|a, b| self.choices[*b].cmp(&self.choices[*a]).then(a.cmp(b)));
+                ranking.truncate(seats as usize);
+                ranking
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn remove_vote(&mut self, sign: &SignType, weight: u64) {
+        match sign {
+            SignType::Agreed => {
+                self.positive = self.positive.saturating_sub(1);
+                self.positive_weight = self.positive_weight.saturating_sub(weight);
+            }
+            SignType::Denied => {
+                self.negative = self.negative.saturating_sub(1);
+                self.negative_weight = self.negative_weight.saturating_sub(weight);
+            }
+            SignType::Abstain => {
+                self.abstain = self.abstain.saturating_sub(1);
+                self.abstain_weight = self.abstain_weight.saturating_sub(weight);
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn participated_weight(&self) -> u64 {
+        self.positive_weight
+            .saturating_add(self.negative_weight)
+            .saturating_add(self.abstain_weight)
+    }
+
+    // abstentions are not taken into account
+    #[inline]
+    pub fn is_approved(&self, weight: VoteWeight, threshold: &ParticipateRate) -> bool {
+        match weight {
+            VoteWeight::Headcount => threshold.is_exceeded_by(
+                self.positive as u64,
+                (self.positive + self.negative) as u64,
+            ),
+            VoteWeight::Token | VoteWeight::Quadratic => threshold.is_exceeded_by(
+                self.positive_weight,
+                self.positive_weight.saturating_add(self.negative_weight),
+            ),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_draft(&self) -> bool {
+        matches!(self.status, ProposalStatus::Draft { .. })
+    }
+
+    pub fn is_draft_expired(&self, now: i64) -> bool {
+        match self.status {
+            ProposalStatus::Draft { expire } => now > expire,
+            _ => false,
+        }
+    }
+
+    /// Count a sponsor, opening the proposal for voting once `required` is met.
+    pub fn add_sponsor(&mut self, required: u8) -> bool {
+        self.sponsors += 1;
+        if self.sponsors >= required as u16 {
+            self.status = ProposalStatus::Progressing;
+            return true;
+        }
+        false
+    }
+
+    #[inline(always)]
+    pub fn is_progressing(&self) -> bool {
+        matches!(
+            self.status,
+            ProposalStatus::Progressing | ProposalStatus::Updated { .. }
+        )
+    }
+
+    // no longer in draft, open for votes or waiting in the timelock
+    pub fn is_finished(&self) -> bool {
+        !(self.is_draft()
+            || self.is_progressing()
+            || matches!(self.status, ProposalStatus::Queued { .. }))
+    }
+
+    /// Clear every lock flag set on the beneficiary and the group by `submit_proposal`.
+    pub fn release_locks(&self, group: &mut GroupAccount, bene_member: &mut MemberAccount) {
+        match self.proposal_type {
+            ProposalType::Upgrade | ProposalType::Downgrade | ProposalType::Remove => {
+                bene_member.in_promotion = false;
+            }
+            ProposalType::UpdateGroup { .. } => {
+                group.update = false;
+            }
+            ProposalType::ReElection => {
+                bene_member.in_promotion = false;
+                group.electing = false;
+            }
+            ProposalType::Withdraw { .. } => {
+                bene_member.in_withdraw = false;
+            }
+            ProposalType::Execute { .. } => {}
+            ProposalType::Election { .. } => {
+                group.electing = false;
+            }
+            ProposalType::Signal { .. } => {}
+        }
     }
 }
 
@@ -67,7 +461,20 @@ mod tests {
 
     #[test]
     pub fn test_proposal_size() {
-        assert_eq!(ProposalAccount::LEN, std::mem::size_of::<ProposalAccount>());
+        // title and uri are stored inline up to their bound
+        assert_eq!(
+            ProposalAccount::LEN - (4 + MAX_TITLE_LEN + 4 + MAX_URI_LEN),
+            std::mem::size_of::<ProposalAccount>() - 2 * std::mem::size_of::<String>()
+        );
+
+        let proposal = ProposalAccount {
+            title: "t".repeat(MAX_TITLE_LEN),
+            uri: "u".repeat(MAX_URI_LEN),
+            content_hash: [1; 32],
+            ..ProposalAccount::default()
+        };
+        let data = AnchorSerialize::try_to_vec(&proposal).unwrap();
+        assert!(data.len() <= ProposalAccount::LEN);
     }
 
     #[test]
@@ -291,4 +698,380 @@ mod tests {
         let res: ProposalAccount = AnchorDeserialize::deserialize(&mut data.as_slice()).unwrap();
         assert_eq!(proposal, res);
     }
+
+    #[test]
+    pub fn test_upgrade_expired() {
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::Upgrade,
+            status: ProposalStatus::Expired { time: 1642650232 },
+            ..ProposalAccount::default()
+        };
+        let mut data = vec![0u8; ProposalAccount::LEN];
+        assert_eq!(data.len(), ProposalAccount::LEN);
+        assert!(AnchorSerialize::serialize(&proposal, &mut data.as_mut_slice()).is_ok());
+        let res: ProposalAccount = AnchorDeserialize::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(proposal, res);
+    }
+
+    #[test]
+    pub fn test_withdraw_queued() {
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::Withdraw {
+                mint: Pubkey::default(),
+                receiver: Pubkey::default(),
+                amount: 1000000,
+            },
+            status: ProposalStatus::Queued { eta: 1642650232 },
+            ..ProposalAccount::default()
+        };
+        let mut data = vec![0u8; ProposalAccount::LEN];
+        assert_eq!(data.len(), ProposalAccount::LEN);
+        assert!(AnchorSerialize::serialize(&proposal, &mut data.as_mut_slice()).is_ok());
+        let res: ProposalAccount = AnchorDeserialize::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(proposal, res);
+        assert!(!res.is_progressing());
+    }
+
+    #[test]
+    pub fn test_execute_progressing() {
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::Execute { instructions: 2 },
+            status: ProposalStatus::Progressing,
+            ..ProposalAccount::default()
+        };
+        let mut data = vec![0u8; ProposalAccount::LEN];
+        assert_eq!(data.len(), ProposalAccount::LEN);
+        assert!(AnchorSerialize::serialize(&proposal, &mut data.as_mut_slice()).is_ok());
+        let res: ProposalAccount = AnchorDeserialize::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(proposal, res);
+    }
+
+    #[test]
+    pub fn test_release_locks() {
+        let mut group = GroupAccount::default();
+        group.electing = true;
+        group.update = true;
+        let mut bene_member = MemberAccount {
+            in_promotion: true,
+            in_withdraw: true,
+            ..MemberAccount::default()
+        };
+
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::ReElection,
+            ..ProposalAccount::default()
+        };
+        proposal.release_locks(&mut group, &mut bene_member);
+        assert!(!group.electing);
+        assert!(group.update);
+        assert!(!bene_member.in_promotion);
+        assert!(bene_member.in_withdraw);
+
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::Withdraw {
+                mint: Pubkey::default(),
+                receiver: Pubkey::default(),
+                amount: 1000000,
+            },
+            ..ProposalAccount::default()
+        };
+        proposal.release_locks(&mut group, &mut bene_member);
+        assert!(!bene_member.in_withdraw);
+
+        let proposal = ProposalAccount {
+            proposal_type: ProposalType::UpdateGroup { max_manager: 10 },
+            ..ProposalAccount::default()
+        };
+        proposal.release_locks(&mut group, &mut bene_member);
+        assert!(!group.update);
+    }
+
+    #[test]
+    pub fn test_approved_by_weight() {
+        let proposal = ProposalAccount {
+            positive: 2,
+            negative: 1,
+            positive_weight: 20,
+            negative_weight: 100,
+            ..ProposalAccount::default()
+        };
+        let majority = ParticipateRate::new(1, 2);
+        assert!(proposal.is_approved(VoteWeight::Headcount, &majority));
+        assert!(!proposal.is_approved(VoteWeight::Token, &majority));
+        assert!(!proposal.is_approved(VoteWeight::Headcount, &ParticipateRate::new(2, 3)));
+        assert_eq!(proposal.participated(), 3);
+        assert_eq!(proposal.participated_weight(), 120);
+    }
+
+    #[test]
+    pub fn test_abstain_counts_toward_quorum_only() {
+        let proposal = ProposalAccount {
+            positive: 1,
+            negative: 1,
+            abstain: 3,
+            positive_weight: 10,
+            negative_weight: 10,
+            abstain_weight: 30,
+            ..ProposalAccount::default()
+        };
+        assert_eq!(proposal.participated(), 5);
+        assert_eq!(proposal.participated_weight(), 50);
+        let majority = ParticipateRate::new(1, 2);
+        assert!(!proposal.is_approved(VoteWeight::Headcount, &majority));
+        assert!(!proposal.is_approved(VoteWeight::Token, &majority));
+    }
+
+    #[test]
+    pub fn test_participated_widened() {
+        let proposal = ProposalAccount {
+            positive: u32::MAX,
+            negative: u32::MAX,
+            abstain: 1,
+            ..ProposalAccount::default()
+        };
+        assert_eq!(proposal.participated(), 2 * u32::MAX as u64 + 1);
+    }
+
+    #[test]
+    pub fn test_change_and_retract_vote() {
+        let mut proposal = ProposalAccount::default();
+        proposal.add_vote(&SignType::Agreed, 10);
+        proposal.add_vote(&SignType::Denied, 20);
+        assert_eq!((proposal.positive, proposal.negative), (1, 1));
+
+        proposal.remove_vote(&SignType::Denied, 20);
+        proposal.add_vote(&SignType::Agreed, 20);
+        assert_eq!((proposal.positive, proposal.negative), (2, 0));
+        assert_eq!((proposal.positive_weight, proposal.negative_weight), (30, 0));
+
+        proposal.remove_vote(&SignType::Agreed, 10);
+        assert_eq!(proposal.participated(), 1);
+        assert_eq!(proposal.participated_weight(), 20);
+    }
+
+    #[test]
+    pub fn test_tally_mode() {
+        let mut group = GroupAccount::default();
+        group.rate = ParticipateRate::new(1, 2);
+        let mut proposal = ProposalAccount {
+            voters: 5,
+            positive: 3,
+            ..ProposalAccount::default()
+        };
+
+        assert_eq!(proposal.tally(&group, false), Some(true));
+        group.config.tally = TallyMode::FullPeriod;
+        assert_eq!(proposal.tally(&group, false), None);
+        assert_eq!(proposal.tally(&group, true), Some(true));
+
+        // 3 of 5 agreed, the remaining 2 can not flip a majority
+        group.config.tally = TallyMode::Decisive;
+        assert_eq!(proposal.tally(&group, false), Some(true));
+
+        proposal.positive = 2;
+        proposal.negative = 1;
+        assert_eq!(proposal.tally(&group, false), None);
+        proposal.negative = 2;
+        assert_eq!(proposal.tally(&group, false), None);
+        proposal.negative = 3;
+        assert_eq!(proposal.tally(&group, false), Some(false));
+
+        // quorum not reached
+        proposal.positive = 1;
+        proposal.negative = 0;
+        assert_eq!(proposal.tally(&group, true), None);
+
+        proposal.positive = 3;
+        proposal.negative = 0;
+        group.config.weight = VoteWeight::Token;
+        proposal.positive_weight = 300;
+        assert_eq!(proposal.tally(&group, false), None);
+        assert_eq!(proposal.tally(&group, true), Some(true));
+    }
+
+    #[test]
+    pub fn test_election() {
+        let mut proposal = ProposalAccount {
+            proposal_type: ProposalType::Election {
+                candidates: 4,
+                seats: 2,
+                ranked: true,
+            },
+            ..ProposalAccount::default()
+        };
+        let agreed = SignType::Agreed;
+        assert!(proposal.proposal_type.is_valid_ballot(&agreed, &[2, 0, 1]));
+        assert!(!proposal.proposal_type.is_valid_ballot(&agreed, &[]));
+        assert!(!proposal.proposal_type.is_valid_ballot(&agreed, &[4]));
+        assert!(!proposal.proposal_type.is_valid_ballot(&agreed, &[1, 1]));
+        assert!(!proposal.proposal_type.is_valid_ballot(&agreed, &[0, 1, 2, 3, 0]));
+        assert!(proposal.proposal_type.is_valid_ballot(&SignType::Denied, &[]));
+        assert!(!proposal.proposal_type.is_valid_ballot(&SignType::Denied, &[0]));
+        assert!(!ProposalType::Upgrade.is_valid_ballot(&agreed, &[0]));
+
+        // borda: 4, 3, 2 points
+        proposal.add_choices(&[2, 0, 1], 1);
+        proposal.add_choices(&[0, 3], 1);
+        assert_eq!(&proposal.choices[..4], &[7, 2, 4, 3]);
+        assert_eq!(proposal.elected(), vec![0, 2]);
+
+        proposal.remove_choices(&[0, 3], 1);
+        assert_eq!(&proposal.choices[..4], &[3, 2, 4, 0]);
+        assert_eq!(proposal.elected(), vec![2, 0]);
+
+        // approval: one point per approved candidate, ties by index
+        proposal.proposal_type = ProposalType::Election {
+            candidates: 4,
+            seats: 3,
+            ranked: false,
+        };
+        proposal.choices = [0; MAX_CHOICES];
+        proposal.add_choices(&[3, 1], 10);
+        proposal.add_choices(&[1], 10);
+        assert_eq!(proposal.elected(), vec![1, 3]);
+    }
+
+    #[test]
+    pub fn test_signal() {
+        let mut group = GroupAccount::default();
+        group.rate = ParticipateRate::new(1, 2);
+        let signal = ProposalType::Signal {
+            options: 3,
+            metadata: [1; 32],
+        };
+        assert!(signal.is_valid_ballot(&SignType::Agreed, &[2]));
+        assert!(!signal.is_valid_ballot(&SignType::Agreed, &[3]));
+        assert!(!signal.is_valid_ballot(&SignType::Agreed, &[0, 1]));
+        assert!(!signal.is_valid_ballot(&SignType::Denied, &[]));
+        assert!(signal.is_valid_ballot(&SignType::Abstain, &[]));
+
+        let mut proposal = ProposalAccount {
+            voters: 2,
+            proposal_type: signal,
+            ..ProposalAccount::default()
+        };
+        proposal.add_vote(&SignType::Agreed, 1);
+        proposal.add_choices(&[2], 1);
+        proposal.add_vote(&SignType::Agreed, 1);
+        proposal.add_choices(&[2], 1);
+        assert_eq!(&proposal.choices[..3], &[0, 0, 2]);
+        // signals are never resolved by the tally
+        assert_eq!(proposal.tally(&group, true), None);
+    }
+
+    #[test]
+    pub fn test_draft_sponsors() {
+        let mut proposal = ProposalAccount {
+            status: ProposalStatus::Draft { expire: 100 },
+            ..ProposalAccount::default()
+        };
+        assert!(proposal.is_draft());
+        assert!(!proposal.is_progressing());
+        assert!(!proposal.is_draft_expired(100));
+        assert!(proposal.is_draft_expired(101));
+
+        assert!(!proposal.add_sponsor(2));
+        assert!(proposal.is_draft());
+        assert!(proposal.add_sponsor(2));
+        assert_eq!(proposal.sponsors, 2);
+        assert!(proposal.is_progressing());
+        assert!(!proposal.is_draft_expired(101));
+    }
+
+    #[test]
+    pub fn test_payload() {
+        assert!(ProposalType::Execute { instructions: 1 }.has_payload());
+        assert!(ProposalType::Election {
+            candidates: 2,
+            seats: 1,
+            ranked: false
+        }
+        .has_payload());
+        assert!(!ProposalType::Upgrade.has_payload());
+        assert!(!ProposalType::Remove.has_payload());
+    }
+
+    #[test]
+    pub fn test_bond_settlement() {
+        let mut proposal = ProposalAccount::default();
+        assert_eq!(proposal.is_bond_refundable(), None);
+        proposal.status = ProposalStatus::Queued { eta: 0 };
+        assert_eq!(proposal.is_bond_refundable(), None);
+        proposal.status = ProposalStatus::Passed { time: 0 };
+        assert_eq!(proposal.is_bond_refundable(), Some(true));
+        proposal.status = ProposalStatus::Closed { time: 0 };
+        assert_eq!(proposal.is_bond_refundable(), Some(true));
+        proposal.status = ProposalStatus::Rejected { time: 0 };
+        assert_eq!(proposal.is_bond_refundable(), Some(false));
+        proposal.status = ProposalStatus::Expired { time: 0 };
+        assert_eq!(proposal.is_bond_refundable(), Some(false));
+        proposal.status = ProposalStatus::Vetoed {
+            time: 0,
+            by: Pubkey::default(),
+        };
+        assert_eq!(proposal.is_bond_refundable(), Some(false));
+    }
+
+    #[test]
+    pub fn test_voter_eligibility() {
+        let withdraw = ProposalType::Withdraw {
+            mint: Pubkey::default(),
+            receiver: Pubkey::default(),
+            amount: 1000000,
+        };
+        assert!(withdraw.is_voter(&Position::Manager));
+        assert!(!withdraw.is_voter(&Position::Member));
+        assert!(ProposalType::Upgrade.is_voter(&Position::Member));
+        assert!(ProposalType::ReElection.is_voter(&Position::Member));
+
+        let mut group = GroupAccount::default();
+        group.current_manager = 2;
+        group.current_member = 5;
+        assert_eq!(group.eligible_voters(&withdraw), 2);
+        assert_eq!(group.eligible_voters(&ProposalType::Downgrade), 7);
+
+        // promoted after the withdraw was submitted, so not counted in its voters
+        let promoted = MemberAccount {
+            position: Position::Manager,
+            positioned_at: 200,
+            ..MemberAccount::default()
+        };
+        let mut proposal = ProposalAccount {
+            proposal_type: withdraw,
+            snapshot_at: 100,
+            ..ProposalAccount::default()
+        };
+        assert!(!proposal.counts_position_of(&promoted));
+        proposal.snapshot_at = 200;
+        assert!(proposal.counts_position_of(&promoted));
+        proposal.snapshot_at = 100;
+        proposal.proposal_type = ProposalType::Downgrade;
+        assert!(proposal.counts_position_of(&promoted));
+    }
+
+    #[test]
+    pub fn test_finished() {
+        let mut proposal = ProposalAccount::default();
+        assert!(!proposal.is_finished());
+        proposal.status = ProposalStatus::Draft { expire: 1 };
+        assert!(!proposal.is_finished());
+        proposal.status = ProposalStatus::Queued { eta: 1 };
+        assert!(!proposal.is_finished());
+        proposal.status = ProposalStatus::Updated { time: 1 };
+        assert!(!proposal.is_finished());
+        proposal.status = ProposalStatus::Expired { time: 1 };
+        assert!(proposal.is_finished());
+        proposal.status = ProposalStatus::Closed { time: 1 };
+        assert!(proposal.is_finished());
+    }
+
+    #[test]
+    pub fn test_voted_after_retraction() {
+        let mut proposal = ProposalAccount::default();
+        assert!(!proposal.voted);
+        proposal.add_vote(&SignType::Agreed, 10);
+        proposal.remove_vote(&SignType::Agreed, 10);
+        assert_eq!(proposal.participated(), 0);
+        assert!(proposal.voted);
+    }
 }
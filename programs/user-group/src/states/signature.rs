@@ -5,18 +5,23 @@ use crate::prelude::*;
 pub enum SignType {
     Agreed,
     Denied,
+    Abstain,
 }
 #[account]
 pub struct SignatureAccount {
     pub signer: Pubkey,
     pub created_at: i64,
     pub amount: u64,
+    pub weight: u64, // effective weight of `amount` in the tally
     pub proposal: Pubkey,
+    pub member: Pubkey, // member the vote is cast for, differs from signer's when delegated
     pub sign_type: SignType,
+    pub commitment: Option<[u8; 32]>, // hash(proposal || member || sign_type || salt) until revealed
+    pub choices: Vec<u8>,             // ranked candidates or chosen options
 }
 impl SignatureAccount {
     pub const SEEDS: &'static [u8] = b"signature";
-    pub const LEN: usize = (1 + 32 + 8 + 8 + 32 + 1) as usize;
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 8 + 32 + 32 + 1 + 33 + 4 + MAX_CHOICES;
 
     #[inline(always)]
     pub fn is_agreed(&self) -> bool {
@@ -28,6 +33,63 @@ impl SignatureAccount {
     }
     #[inline(always)]
     pub fn is_denied(&self) -> bool {
-        !self.is_agreed()
+        matches!(self.sign_type, SignType::Denied)
+    }
+    #[inline(always)]
+    pub fn is_abstained(&self) -> bool {
+        matches!(self.sign_type, SignType::Abstain)
+    }
+    #[inline(always)]
+    pub fn is_revealed(&self) -> bool {
+        self.commitment.is_none()
+    }
+
+    pub fn commit(
+        proposal: &Pubkey,
+        member: &Pubkey,
+        sign: &SignType,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            proposal.as_ref(),
+            member.as_ref(),
+            &[sign.clone() as u8],
+            salt,
+        ])
+        .to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_commitment() {
+        let salt = [7u8; 32];
+        let proposal = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+        let commitment = SignatureAccount::commit(&proposal, &member, &SignType::Agreed, &salt);
+        assert_eq!(
+            commitment,
+            SignatureAccount::commit(&proposal, &member, &SignType::Agreed, &salt)
+        );
+        assert_ne!(
+            commitment,
+            SignatureAccount::commit(&proposal, &member, &SignType::Denied, &salt)
+        );
+        assert_ne!(
+            commitment,
+            SignatureAccount::commit(&proposal, &member, &SignType::Agreed, &[8u8; 32])
+        );
+        // a commitment can not be replayed on another proposal or by another member
+        assert_ne!(
+            commitment,
+            SignatureAccount::commit(&Pubkey::new_unique(), &member, &SignType::Agreed, &salt)
+        );
+        assert_ne!(
+            commitment,
+            SignatureAccount::commit(&proposal, &Pubkey::new_unique(), &SignType::Agreed, &salt)
+        );
     }
 }
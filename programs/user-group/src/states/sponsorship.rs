@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+#[account]
+#[derive(Debug, Default)]
+pub struct SponsorshipAccount {
+    pub proposal: Pubkey,
+    pub member: Pubkey, // sponsor member account
+    pub sponsor: Pubkey,
+    pub created_at: i64,
+}
+impl SponsorshipAccount {
+    pub const SEEDS: &'static [u8] = b"sponsor";
+    pub const LEN: usize = 32 + 32 + 32 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_sponsorship_size() {
+        assert_eq!(SponsorshipAccount::LEN, std::mem::size_of::<SponsorshipAccount>());
+    }
+}
@@ -2,6 +2,161 @@ use crate::prelude::*;
 
 const TIME_ELAPSE: i64 = 43200; // 12 hours
 
+// create `target` even if someone has sent lamports to its address beforehand
+pub(crate) fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &Rent,
+    space: usize,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let required = rent.minimum_balance(space);
+    let lamports = target.lamports();
+    if lamports == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            required,
+            space as u64,
+            program_id,
+        );
+    }
+
+    if lamports < required {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            required - lamports,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            &[seeds],
+        ),
+        program_id,
+    )
+}
+
+// close `target` for good: drain its lamports, wipe its data and hand it back to
+// the system program, so a refund in the same transaction can not revive it
+pub(crate) fn close_pda_account<'info>(
+    target: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = target.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::InvalidArgument)?;
+    **target.try_borrow_mut_lamports()? = 0;
+    target.try_borrow_mut_data()?.fill(0);
+    target.realloc(0, false)?;
+    target.assign(&System::id());
+    Ok(())
+}
+
+// `escrow_info` must be the group stake escrow of `mint`, the mint the stake was taken in
+pub(crate) fn is_stake_escrow(
+    group: &Pubkey,
+    mint: &Pubkey,
+    escrow_info: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (address, _) = Pubkey::find_program_address(
+        &[group.as_ref(), mint.as_ref(), MembershipConfig::STAKE_SEEDS],
+        program_id,
+    );
+    let escrow = Account::<TokenAccount>::try_from(escrow_info)?;
+    if escrow_info.key() != address || escrow.owner != *group || escrow.mint != *mint {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::WrongVaultTokenAccount)
+        } else {
+            Err(GroupError::WrongVaultTokenAccount.into())
+        };
+    }
+    Ok(())
+}
+
+// `escrow_info` must be the vote or bond escrow of `proposal`, picked by `seeds`
+pub(crate) fn is_proposal_escrow(
+    proposal: &Pubkey,
+    seeds: &[u8],
+    escrow_info: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (address, _) = Pubkey::find_program_address(&[proposal.as_ref(), seeds], program_id);
+    let escrow = Account::<TokenAccount>::try_from(escrow_info)?;
+    if escrow_info.key() != address || escrow.owner != *proposal {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::WrongVaultTokenAccount)
+        } else {
+            Err(GroupError::WrongVaultTokenAccount.into())
+        };
+    }
+    Ok(())
+}
+
+// member account of the beneficiary of `proposal`, `None` once closed by a Remove or an exit
+pub(crate) fn load_bene_member<'info>(
+    proposal: &ProposalAccount,
+    bene_member_info: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<Option<Account<'info, MemberAccount>>> {
+    if bene_member_info.key() != proposal.bene_member {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::MismatchedBeneMember)
+        } else {
+            Err(GroupError::MismatchedBeneMember.into())
+        };
+    }
+    if bene_member_info.owner != program_id {
+        return Ok(None);
+    }
+    let bene_member = Account::<MemberAccount>::try_from(bene_member_info)?;
+    if bene_member.group != proposal.group {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::MismatchedGroup)
+        } else {
+            Err(GroupError::MismatchedGroup.into())
+        };
+    }
+    if bene_member.owner != proposal.beneficiary {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::MismatchedBeneMember)
+        } else {
+            Err(GroupError::MismatchedBeneMember.into())
+        };
+    }
+    Ok(Some(bene_member))
+}
+
 pub(crate) fn is_admin(admin: &AdminAccount, user: &Pubkey) -> Result<()> {
     if !admin.is_admin(user) {
         return if cfg!(feature = "dev") {
@@ -46,6 +201,17 @@ pub(crate) fn is_valid_limit(limit: u64) -> Result<()> {
     Ok(())
 }
 
+pub(crate) fn is_valid_metadata(title: &str, uri: &str) -> Result<()> {
+    if !ProposalAccount::is_valid_metadata(title, uri) {
+        return if cfg!(feature = "dev") {
+            err!(GroupError::MetadataTooLong)
+        } else {
+            Err(GroupError::MetadataTooLong.into())
+        };
+    }
+    Ok(())
+}
+
 pub(crate) fn is_not_outdated(proposal: &ProposalAccount, now: i64) -> Result<()> {
     if now > proposal.revoke_timeout {
         return if cfg!(feature = "dev") {
@@ -68,10 +234,32 @@ pub(crate) fn is_valid_elapse(proposal: &ProposalAccount, now: i64) -> Result<()
     Ok(())
 }
 
+pub(crate) fn is_executable(proposal: &ProposalAccount, now: i64) -> Result<()> {
+    match proposal.status {
+        ProposalStatus::Queued { eta } => {
+            if now < eta {
+                return if cfg!(feature = "dev") {
+                    err!(GroupError::ProposalNotReady)
+                } else {
+                    Err(GroupError::ProposalNotReady.into())
+                };
+            }
+        }
+        _ => {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::ProposalNotQueued)
+            } else {
+                Err(GroupError::ProposalNotQueued.into())
+            };
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn is_proposal_allow_close(proposal: &ProposalAccount, now: i64) -> Result<()> {
     match proposal.status {
         ProposalStatus::Progressing | ProposalStatus::Updated { .. } => {
-            if proposal.deadline > now {
+            if proposal.voting_end() > now {
                 return if cfg!(feature = "dev") {
                     err!(GroupError::NotAllowedToCloseProgressingProposal)
                 } else {
@@ -79,6 +267,13 @@ pub(crate) fn is_proposal_allow_close(proposal: &ProposalAccount, now: i64) -> R
                 };
             }
         }
+        ProposalStatus::Queued { .. } | ProposalStatus::Draft { .. } => {
+            return if cfg!(feature = "dev") {
+                err!(GroupError::NotAllowedToCloseProgressingProposal)
+            } else {
+                Err(GroupError::NotAllowedToCloseProgressingProposal.into())
+            };
+        }
         _ => {}
     }
     Ok(())
@@ -16,6 +16,62 @@ type Publickey = anchor.web3.PublicKey;
 type Keypair = anchor.web3.Keypair;
 const LAMPORTS_PER_SOL = anchor.web3.LAMPORTS_PER_SOL;
 
+async function joinRequestAddress(group: Publickey, user: Publickey): Promise<Publickey> {
+  const [joinRequest] = await anchor.web3.PublicKey.findProgramAddress(
+    [group.toBuffer(), user.toBuffer(), Buffer.from("join_request")],
+    program.programId,
+  );
+  return joinRequest;
+}
+
+async function memberAddress(group: Publickey, user: Publickey): Promise<Publickey> {
+  const [member] = await anchor.web3.PublicKey.findProgramAddress(
+    [group.toBuffer(), user.toBuffer(), Buffer.from("member")],
+    program.programId,
+  );
+  return member;
+}
+
+async function nextProposalAddress(group: Publickey): Promise<Publickey> {
+  const groupAccount = await program.account.groupAccount.fetch(group);
+  const buf = Buffer.alloc(4);
+  u32().encode(groupAccount.proposals, buf);
+  const [proposal] = await anchor.web3.PublicKey.findProgramAddress(
+    [group.toBuffer(), buf, Buffer.from("proposal")],
+    program.programId,
+  );
+  return proposal;
+}
+
+function voteConfig(overrides: object = {}) {
+  const majority = {numerator: 1, denominator: 2};
+  return {
+    quorumWeight: new anchor.BN(0),
+    timelock: new anchor.BN(0),
+    revealPeriod: new anchor.BN(0),
+    bond: new anchor.BN(0),
+    draftPeriod: new anchor.BN(0),
+    weight: {headcount: {}},
+    quorum: {headcount: {}},
+    lock: {burn: {}},
+    tally: {earlyQuorum: {}},
+    slash: {burn: {}},
+    sponsors: 0,
+    thresholds: {
+      upgrade: majority,
+      downgrade: majority,
+      updateGroup: majority,
+      reElection: majority,
+      withdraw: majority,
+      execute: majority,
+      election: majority,
+      remove: majority,
+    },
+    padding: [0, 0],
+    ...overrides,
+  };
+}
+
 describe('user-group', () => {
   // Configure the client to use the local cluster.
   const payer: Keypair = anchor.web3.Keypair.generate();
@@ -228,12 +284,16 @@ describe('user-group', () => {
     print(`memberOne Pubkey: ${memberOne.toBase58()}`);
 
     const tx2 = await program.rpc.joinGroup(
+      [],
+      null,
+      null,
       {
         accounts: {
           authority: payer.publicKey,
           user: userOneKeypair.publicKey,
           group: group,
           member: memberOne,
+          joinRequest: await joinRequestAddress(group, userOneKeypair.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -343,12 +403,16 @@ describe('user-group', () => {
     print(`memberTwo Pubkey: ${memberTwo.toBase58()}`);
 
     const tx = await program.rpc.joinGroup(
+      [],
+      null,
+      null,
       {
         accounts: {
           authority: userTwoKeypair.publicKey,
           user: userTwoKeypair.publicKey,
           group,
           member: memberTwo,
+          joinRequest: await joinRequestAddress(group, userTwoKeypair.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -397,12 +461,16 @@ describe('user-group', () => {
 
   it("join group", async () => {
     const tx = await program.rpc.joinGroup(
+      [],
+      null,
+      null,
       {
         accounts: {
           authority: userTwoKeypair.publicKey,
           user: userTwoKeypair.publicKey,
           group,
           member: memberTwo,
+          joinRequest: await joinRequestAddress(group, userTwoKeypair.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -424,12 +492,16 @@ describe('user-group', () => {
     print(`memberThree Pubkey: ${memberThree.toBase58()}`);
 
     const tx2 = await program.rpc.joinGroup(
+      [],
+      null,
+      null,
       {
         accounts: {
           authority: userThreeKeypair.publicKey,
           user: userThreeKeypair.publicKey,
           group,
           member: memberThree,
+          joinRequest: await joinRequestAddress(group, userThreeKeypair.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -460,6 +532,9 @@ describe('user-group', () => {
       {upgrade: {}},
       new anchor.BN(10 * (10 ** decimals)),
       new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
       {
         accounts: {
           authority: userOneKeypair.publicKey,
@@ -540,6 +615,9 @@ describe('user-group', () => {
       {upgrade: {}},
       new anchor.BN(10 * (10 ** decimals)),
       new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
       {
         accounts: {
           authority: userOneKeypair.publicKey,
@@ -583,13 +661,18 @@ describe('user-group', () => {
 
     const tx2 = await program.rpc.signProposal(
       {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
       {
         accounts: {
           authority: userOneKeypair.publicKey,
+          admin,
           mint: burnTokenMintKeypair.publicKey,
           group,
           token: userOneBurnToken,
+          vault: groupBurnToken,
           member: memberOne,
+          beneMember: memberTwo,
           signature,
           proposal,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -597,9 +680,6 @@ describe('user-group', () => {
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         },
-        remainingAccounts: [
-          {pubkey: memberTwo, isSigner: false, isWritable: true},
-        ],
         signers: [userOneKeypair],
       }
     );
@@ -611,6 +691,20 @@ describe('user-group', () => {
     assert.equal(proposalAccount.negative.toString(), "0");
     print(`proposal status: ${JSON.stringify(proposalAccount.status)}`);
 
+    const tx5 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          beneMember: memberTwo,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`execute proposal transaction: ${tx5}`);
+
     memberAccount = await program.account.memberAccount.fetch(memberTwo);
     assert.isFalse(memberAccount.inPromotion);
     assert.equal(JSON.stringify(memberAccount.position), JSON.stringify({manager: {}}));
@@ -636,8 +730,11 @@ describe('user-group', () => {
         accounts: {
           authority: userOneKeypair.publicKey,
           group,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
           member: memberOne,
           proposal,
+          signer: userOneKeypair.publicKey,
           signature,
           systemProgram: anchor.web3.SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -694,6 +791,9 @@ describe('user-group', () => {
       {downgrade: {}},
       new anchor.BN(10 * (10 ** decimals)),
       new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
       {
         accounts: {
           authority: userTwoKeypair.publicKey,
@@ -723,13 +823,18 @@ describe('user-group', () => {
 
     const tx2 = await program.rpc.signProposal(
       {denied: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
       {
         accounts: {
           authority: userOneKeypair.publicKey,
+          admin,
           mint: burnTokenMintKeypair.publicKey,
           group,
           token: userOneBurnToken,
+          vault: groupBurnToken,
           member: memberOne,
+          beneMember: memberOne,
           signature,
           proposal,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -737,9 +842,6 @@ describe('user-group', () => {
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         },
-        remainingAccounts: [
-          {pubkey: memberOne, isSigner: false, isWritable: true},
-        ],
         signers: [userOneKeypair],
       }
     );
@@ -774,6 +876,9 @@ describe('user-group', () => {
         {updateGroup: {maxManager: new anchor.BN(20)}},
         new anchor.BN(10 * (10 ** decimals)),
         new anchor.BN(deadline / 1000),
+        "",
+        "",
+        Array(32).fill(0),
         {
           accounts: {
             authority: userOneKeypair.publicKey,
@@ -815,6 +920,9 @@ describe('user-group', () => {
         {upgrade: {}},
         new anchor.BN(10 * (10 ** decimals)),
         new anchor.BN(deadline / 1000),
+        "",
+        "",
+        Array(32).fill(0),
         {
           accounts: {
             authority: userThreeKeypair.publicKey,
@@ -856,6 +964,9 @@ describe('user-group', () => {
       {withdraw: {mint: burnTokenMintKeypair.publicKey, receiver: userOneBurnToken, amount: new anchor.BN(100 * (10 ** decimals))}},
       new anchor.BN(10 * (10 ** decimals)),
       new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
       {
         accounts: {
           authority: userOneKeypair.publicKey,
@@ -887,13 +998,18 @@ describe('user-group', () => {
 
     const tx2 = await program.rpc.signProposal(
       {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
       {
         accounts: {
           authority: userTwoKeypair.publicKey,
+          admin,
           mint: burnTokenMintKeypair.publicKey,
           group,
           token: userTwoBurnToken,
+          vault: groupBurnToken,
           member: memberTwo,
+          beneMember: memberOne,
           signature,
           proposal,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -901,15 +1017,28 @@ describe('user-group', () => {
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         },
+        signers: [userTwoKeypair],
+      }
+    );
+    print(`user two sign proposal transaction: ${tx2}`);
+
+    const tx3 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userTwoKeypair.publicKey,
+          beneMember: memberOne,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
         remainingAccounts: [
-          {pubkey: memberOne, isSigner: false, isWritable: true},
           {pubkey: groupBurnToken, isSigner: false, isWritable: true},
           {pubkey: userOneBurnToken, isSigner: false, isWritable: true},
         ],
         signers: [userTwoKeypair],
       }
     );
-    print(`user two sign proposal transaction: ${tx2}`);
+    print(`execute proposal transaction: ${tx3}`);
 
     groupTokenAccount = await spl.getAccount(provider.connection, groupBurnToken);
     assert.equal(groupTokenAccount.amount.toString(), "400000000");
@@ -975,6 +1104,9 @@ describe('user-group', () => {
       {reElection: {}},
       new anchor.BN(10 * (10 ** decimals)),
       new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
       {
         accounts: {
           authority: userThreeKeypair.publicKey,
@@ -1007,13 +1139,18 @@ describe('user-group', () => {
 
     const tx4 = await program.rpc.signProposal(
       {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
       {
         accounts: {
           authority: userThreeKeypair.publicKey,
+          admin,
           mint: burnTokenMintKeypair.publicKey,
           group,
           token: userThreeBurnToken,
+          vault: groupBurnToken,
           member: memberThree,
+          beneMember: memberThree,
           signature,
           proposal,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -1021,14 +1158,25 @@ describe('user-group', () => {
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         },
-        remainingAccounts: [
-          {pubkey: memberThree, isSigner: false, isWritable: true},
-        ],
         signers: [userThreeKeypair],
       }
     );
     print(`user three sign proposal transaction: ${tx4}`);
 
+    const tx5 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneMember: memberThree,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`execute proposal transaction: ${tx5}`);
+
     groupAccount = await program.account.groupAccount.fetch(group);
     assert.equal(groupAccount.currentManager.toString(), "1");
     assert.equal(groupAccount.currentMember.toString(), "0");
@@ -1041,6 +1189,730 @@ describe('user-group', () => {
     print("complete submit proposal reelection");
   })
 
+  it("refund escrowed vote to the signer", async () => {
+    // bring user one and two back as members
+    memberOne = await memberAddress(group, userOneKeypair.publicKey);
+    memberTwo = await memberAddress(group, userTwoKeypair.publicKey);
+    for (const [user, member] of [[userOneKeypair, memberOne], [userTwoKeypair, memberTwo]] as [Keypair, Publickey][]) {
+      const tx = await program.rpc.joinGroup(
+        [],
+        null,
+        null,
+        {
+          accounts: {
+            authority: user.publicKey,
+            user: user.publicKey,
+            group,
+            member,
+            joinRequest: await joinRequestAddress(group, user.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+          },
+          signers: [user],
+        }
+      );
+      print(`Joined group transaction: ${tx}`);
+    }
+
+    const tx = await program.rpc.configureGroup(
+      voteConfig({lock: {escrow: {}}}),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    print(`configure group transaction: ${tx}`);
+
+    const proposal = await nextProposalAddress(group);
+    const deadline = new Date().getTime() + 43200 * 1000;
+    const tx2 = await program.rpc.submitProposal(
+      {upgrade: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneficiary: userTwoKeypair.publicKey,
+          proposal,
+          group,
+          member: memberThree,
+          beneMember: memberTwo,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`submit proposal transaction: ${tx2}`);
+
+    const [escrow] = await anchor.web3.PublicKey.findProgramAddress(
+      [proposal.toBuffer(), Buffer.from("vote_escrow")],
+      program.programId,
+    );
+    const tx3 = await program.rpc.createVoteEscrow(
+      {
+        accounts: {
+          authority: payer.publicKey,
+          group,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          proposal,
+          voteEscrow: escrow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        signers: [payer],
+      }
+    );
+    print(`create vote escrow transaction: ${tx3}`);
+
+    const [signature] = await anchor.web3.PublicKey.findProgramAddress(
+      [group.toBuffer(), memberOne.toBuffer(), proposal.toBuffer()],
+      program.programId,
+    );
+    const balance = (await spl.getAccount(provider.connection, userOneBurnToken)).amount;
+
+    const tx4 = await program.rpc.signProposal(
+      {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          group,
+          token: userOneBurnToken,
+          vault: escrow,
+          member: memberOne,
+          beneMember: memberTwo,
+          signature,
+          proposal,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one sign proposal transaction: ${tx4}`);
+
+    let proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.equal(proposalAccount.escrowed.toString(), "10000000");
+    assert.equal((await spl.getAccount(provider.connection, escrow)).amount.toString(), "10000000");
+
+    // anyone may close the signature, the escrow goes back to the signer
+    const tx5 = await program.rpc.closeSignature(
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          group,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          member: memberOne,
+          proposal,
+          signer: userOneKeypair.publicKey,
+          signature,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        remainingAccounts: [
+          {pubkey: escrow, isSigner: false, isWritable: true},
+          {pubkey: userOneBurnToken, isSigner: false, isWritable: true},
+          {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+        ],
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`close signature transaction: ${tx5}`);
+
+    proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.equal(proposalAccount.escrowed.toString(), "0");
+    assert.equal((await spl.getAccount(provider.connection, escrow)).amount.toString(), "0");
+    assert.equal((await spl.getAccount(provider.connection, userOneBurnToken)).amount.toString(), balance.toString());
+    assert.isNull(await provider.connection.getAccountInfo(signature));
+
+    const tx6 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneMember: memberTwo,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`execute proposal transaction: ${tx6}`);
+
+    const memberTwoAccount = await program.account.memberAccount.fetch(memberTwo);
+    assert.equal(JSON.stringify(memberTwoAccount.position), JSON.stringify({manager: {}}));
+
+    await program.rpc.configureGroup(
+      voteConfig(),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+
+    print("complete refund escrowed vote");
+  });
+
+  it("settle bond of a passed proposal", async () => {
+    const bond = new anchor.BN(5 * (10 ** decimals));
+    const tx = await program.rpc.configureGroup(
+      voteConfig({bond}),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    print(`configure group transaction: ${tx}`);
+
+    const proposal = await nextProposalAddress(group);
+    // the escrow must exist before the bond is taken at submit
+    const [escrow] = await anchor.web3.PublicKey.findProgramAddress(
+      [proposal.toBuffer(), Buffer.from("bond_escrow")],
+      program.programId,
+    );
+    const tx2 = await program.rpc.createBondEscrow(
+      {
+        accounts: {
+          authority: payer.publicKey,
+          group,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          proposal,
+          bondEscrow: escrow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        signers: [payer],
+      }
+    );
+    print(`create bond escrow transaction: ${tx2}`);
+
+    const balance = (await spl.getAccount(provider.connection, userThreeBurnToken)).amount;
+
+    const deadline = new Date().getTime() + 43200 * 1000;
+    const tx3 = await program.rpc.submitProposal(
+      {downgrade: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneficiary: userTwoKeypair.publicKey,
+          proposal,
+          group,
+          member: memberThree,
+          beneMember: memberTwo,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        remainingAccounts: [
+          {pubkey: admin, isSigner: false, isWritable: false},
+          {pubkey: userThreeBurnToken, isSigner: false, isWritable: true},
+          {pubkey: escrow, isSigner: false, isWritable: true},
+          {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+        ],
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`submit proposal transaction: ${tx3}`);
+
+    let proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.equal(proposalAccount.bond.toString(), bond.toString());
+    assert.equal((await spl.getAccount(provider.connection, escrow)).amount.toString(), bond.toString());
+
+    const [signature] = await anchor.web3.PublicKey.findProgramAddress(
+      [group.toBuffer(), memberOne.toBuffer(), proposal.toBuffer()],
+      program.programId,
+    );
+    const tx4 = await program.rpc.signProposal(
+      {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          group,
+          token: userOneBurnToken,
+          vault: groupBurnToken,
+          member: memberOne,
+          beneMember: memberTwo,
+          signature,
+          proposal,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one sign proposal transaction: ${tx4}`);
+
+    const tx5 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneMember: memberTwo,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`execute proposal transaction: ${tx5}`);
+
+    // a passed proposal returns the bond to the submitter
+    const tx6 = await program.rpc.settleBond(
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          group,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          proposal,
+          escrow,
+          submitterToken: userThreeBurnToken,
+          vault: groupBurnToken,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`settle bond transaction: ${tx6}`);
+
+    proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.equal(proposalAccount.bond.toString(), "0");
+    assert.equal((await spl.getAccount(provider.connection, escrow)).amount.toString(), "0");
+    assert.equal((await spl.getAccount(provider.connection, userThreeBurnToken)).amount.toString(), balance.toString());
+
+    const memberTwoAccount = await program.account.memberAccount.fetch(memberTwo);
+    assert.equal(JSON.stringify(memberTwoAccount.position), JSON.stringify({member: {}}));
+
+    await program.rpc.configureGroup(
+      voteConfig(),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+
+    print("complete settle bond");
+  });
+
+  it("veto a queued proposal", async () => {
+    // a timelock keeps the passed proposal queued
+    const tx = await program.rpc.configureGroup(
+      voteConfig({timelock: new anchor.BN(3600)}),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    print(`configure group transaction: ${tx}`);
+
+    const proposal = await nextProposalAddress(group);
+    const deadline = new Date().getTime() + 43200 * 1000;
+    const tx2 = await program.rpc.submitProposal(
+      {upgrade: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneficiary: userTwoKeypair.publicKey,
+          proposal,
+          group,
+          member: memberThree,
+          beneMember: memberTwo,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`submit proposal transaction: ${tx2}`);
+
+    const [signature] = await anchor.web3.PublicKey.findProgramAddress(
+      [group.toBuffer(), memberOne.toBuffer(), proposal.toBuffer()],
+      program.programId,
+    );
+    const tx3 = await program.rpc.signProposal(
+      {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          group,
+          token: userOneBurnToken,
+          vault: groupBurnToken,
+          member: memberOne,
+          beneMember: memberTwo,
+          signature,
+          proposal,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one sign proposal transaction: ${tx3}`);
+
+    let proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.property(proposalAccount.status, "queued");
+
+    const tx4 = await program.rpc.vetoProposal(
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          beneMember: memberTwo,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    print(`veto proposal transaction: ${tx4}`);
+
+    proposalAccount = await program.account.proposalAccount.fetch(proposal);
+    assert.property(proposalAccount.status, "vetoed");
+    assert.equal(proposalAccount.status.vetoed.by.toBase58(), adminKeypair.publicKey.toBase58());
+
+    const memberTwoAccount = await program.account.memberAccount.fetch(memberTwo);
+    assert.isFalse(memberTwoAccount.inPromotion);
+    assert.equal(JSON.stringify(memberTwoAccount.position), JSON.stringify({member: {}}));
+
+    // only queued proposals can be vetoed
+    try {
+      await program.rpc.vetoProposal(
+        {
+          accounts: {
+            authority: adminKeypair.publicKey,
+            admin,
+            beneMember: memberTwo,
+            proposal,
+            group,
+            clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+          },
+          signers: [adminKeypair],
+        }
+      );
+      assert.fail("vetoed proposal is vetoed again");
+    } catch (err) {
+      assert.strictEqual(err.msg, "Only queued proposals can be vetoed");
+    }
+
+    await program.rpc.configureGroup(
+      voteConfig(),
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+
+    print("complete veto proposal");
+  });
+
+  it("refund stake on exit", async () => {
+    const stakeAmount = new anchor.BN(10 * (10 ** decimals));
+    const tx = await program.rpc.configureMembership(
+      {
+        gateAmount: stakeAmount,
+        gateMint: burnTokenMintKeypair.publicKey,
+        allowlist: Array(32).fill(0),
+        policy: {open: {}},
+        gate: {stake: {}},
+        threshold: {numerator: 0, denominator: 0},
+        padding: Array(4).fill(0),
+      },
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    print(`configure membership transaction: ${tx}`);
+
+    const [stakeEscrow] = await anchor.web3.PublicKey.findProgramAddress(
+      [group.toBuffer(), burnTokenMintKeypair.publicKey.toBuffer(), Buffer.from("stake")],
+      program.programId,
+    );
+    const tx2 = await program.rpc.createStakeEscrow(
+      {
+        accounts: {
+          authority: payer.publicKey,
+          group,
+          mint: burnTokenMintKeypair.publicKey,
+          stakeEscrow,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        },
+        signers: [payer],
+      }
+    );
+    print(`create stake escrow transaction: ${tx2}`);
+
+    const tx3 = await program.rpc.exitGroup(
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          funder: userOneKeypair.publicKey,
+          member: memberOne,
+          group,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one exit group transaction: ${tx3}`);
+
+    const balance = (await spl.getAccount(provider.connection, userOneBurnToken)).amount;
+    const tx4 = await program.rpc.joinGroup(
+      [],
+      null,
+      null,
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          user: userOneKeypair.publicKey,
+          group,
+          member: memberOne,
+          joinRequest: await joinRequestAddress(group, userOneKeypair.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        remainingAccounts: [
+          {pubkey: userOneBurnToken, isSigner: false, isWritable: true},
+          {pubkey: stakeEscrow, isSigner: false, isWritable: true},
+          {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+        ],
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one stake and join group transaction: ${tx4}`);
+
+    let memberAccount = await program.account.memberAccount.fetch(memberOne);
+    assert.equal(memberAccount.staked.toString(), stakeAmount.toString());
+    assert.ok(memberAccount.stakeMint.equals(burnTokenMintKeypair.publicKey));
+    assert.equal((await program.account.groupAccount.fetch(group)).stakes, 1);
+    assert.equal((await spl.getAccount(provider.connection, stakeEscrow)).amount.toString(), stakeAmount.toString());
+
+    const tx5 = await program.rpc.exitGroup(
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          funder: userOneKeypair.publicKey,
+          member: memberOne,
+          group,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        remainingAccounts: [
+          {pubkey: stakeEscrow, isSigner: false, isWritable: true},
+          {pubkey: userOneBurnToken, isSigner: false, isWritable: true},
+          {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+        ],
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one exit group with refund transaction: ${tx5}`);
+
+    assert.equal((await spl.getAccount(provider.connection, userOneBurnToken)).amount.toString(), balance.toString());
+    assert.equal((await spl.getAccount(provider.connection, stakeEscrow)).amount.toString(), "0");
+    assert.equal((await program.account.groupAccount.fetch(group)).stakes, 0);
+
+    await program.rpc.configureMembership(
+      {
+        gateAmount: new anchor.BN(0),
+        gateMint: anchor.web3.PublicKey.default,
+        allowlist: Array(32).fill(0),
+        policy: {open: {}},
+        gate: {none: {}},
+        threshold: {numerator: 0, denominator: 0},
+        padding: Array(4).fill(0),
+      },
+      {
+        accounts: {
+          authority: adminKeypair.publicKey,
+          admin,
+          group,
+        },
+        signers: [adminKeypair],
+      }
+    );
+    await program.rpc.joinGroup(
+      [],
+      null,
+      null,
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          user: userOneKeypair.publicKey,
+          group,
+          member: memberOne,
+          joinRequest: await joinRequestAddress(group, userOneKeypair.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+
+    print("complete refund stake on exit");
+  });
+
+  it("remove a member", async () => {
+    const proposal = await nextProposalAddress(group);
+    const deadline = new Date().getTime() + 43200 * 1000;
+    const tx = await program.rpc.submitProposal(
+      {remove: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      new anchor.BN(deadline / 1000),
+      "",
+      "",
+      Array(32).fill(0),
+      {
+        accounts: {
+          authority: userThreeKeypair.publicKey,
+          beneficiary: userTwoKeypair.publicKey,
+          proposal,
+          group,
+          member: memberThree,
+          beneMember: memberTwo,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userThreeKeypair],
+      }
+    );
+    print(`submit proposal transaction: ${tx}`);
+
+    const [signature] = await anchor.web3.PublicKey.findProgramAddress(
+      [group.toBuffer(), memberOne.toBuffer(), proposal.toBuffer()],
+      program.programId,
+    );
+    const tx2 = await program.rpc.signProposal(
+      {agreed: {}},
+      new anchor.BN(10 * (10 ** decimals)),
+      Buffer.from([]),
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          admin,
+          mint: burnTokenMintKeypair.publicKey,
+          group,
+          token: userOneBurnToken,
+          vault: groupBurnToken,
+          member: memberOne,
+          beneMember: memberTwo,
+          signature,
+          proposal,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        signers: [userOneKeypair],
+      }
+    );
+    print(`user one sign proposal transaction: ${tx2}`);
+
+    const groupBefore = await program.account.groupAccount.fetch(group);
+    const funderBefore = await provider.connection.getBalance(userTwoKeypair.publicKey);
+    const tx3 = await program.rpc.executeProposal(
+      {
+        accounts: {
+          authority: userOneKeypair.publicKey,
+          beneMember: memberTwo,
+          proposal,
+          group,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        },
+        remainingAccounts: [
+          {pubkey: userTwoKeypair.publicKey, isSigner: false, isWritable: true},
+        ],
+        signers: [userOneKeypair],
+      }
+    );
+    print(`execute proposal transaction: ${tx3}`);
+
+    // the member account is closed for good, its rent goes back to the funder
+    const info = await provider.connection.getAccountInfo(memberTwo);
+    assert.isNull(info);
+    assert.isAbove(await provider.connection.getBalance(userTwoKeypair.publicKey), funderBefore);
+
+    const groupAfter = await program.account.groupAccount.fetch(group);
+    assert.equal(groupAfter.currentMember.toString(), (groupBefore.currentMember - 1).toString());
+    assert.equal(groupAfter.currentManager.toString(), groupBefore.currentManager.toString());
+
+    print("complete remove a member");
+  });
+
 
 });
 